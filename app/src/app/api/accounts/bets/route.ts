@@ -1,11 +1,22 @@
-import { isWinner } from "@/lib/betType";
+import { hasWinningPosition } from "@/lib/betType";
 import { DISCRIMINATOR_SIZE } from "@/lib/constants";
-import { MAGIC_ROULETTE_CLIENT } from "@/lib/server/solana";
+import { MAGIC_ROULETTE_CLIENT, TABLE } from "@/lib/server/solana";
 import { boolToByte } from "@/lib/utils";
-import { parseBet, parseRound } from "@/types/accounts";
-import { GetProgramAccountsFilter } from "@solana/web3.js";
+import { ParsedBet, parseBet, parseRound } from "@/types/accounts";
+import { GetProgramAccountsFilter, PublicKey } from "@solana/web3.js";
 import { NextRequest, NextResponse } from "next/server";
 
+// bet accounts don't store their table, so it is checked through the bet PDA
+function isTableBet({ publicKey, round, player }: ParsedBet) {
+  const pda = MAGIC_ROULETTE_CLIENT.getBetPda(
+    TABLE,
+    new PublicKey(round),
+    new PublicKey(player)
+  );
+
+  return pda.toBase58() === publicKey;
+}
+
 export async function GET(req: NextRequest) {
   const { searchParams } = new URL(req.url);
 
@@ -55,6 +66,8 @@ export async function GET(req: NextRequest) {
         filters
       );
 
+      bets = bets.filter(isTableBet);
+
       if (isWinning) {
         const round = await MAGIC_ROULETTE_CLIENT.fetchAllProgramAccounts(
           "round",
@@ -68,7 +81,10 @@ export async function GET(req: NextRequest) {
             throw new Error("Bet has no matching round.");
           }
 
-          const isWinningBet = isWinner(bet.betType, matchingRound.outcome);
+          const isWinningBet = hasWinningPosition(
+            bet.positions,
+            matchingRound.outcome
+          );
 
           return isWinning.toLowerCase() === "true"
             ? isWinningBet
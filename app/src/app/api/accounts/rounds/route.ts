@@ -1,11 +1,18 @@
 import { DISCRIMINATOR_SIZE } from "@/lib/constants";
-import { MAGIC_ROULETTE_CLIENT } from "@/lib/server/solana";
+import { MAGIC_ROULETTE_CLIENT, TABLE } from "@/lib/server/solana";
 import { BNtoBase64, boolToByte } from "@/lib/utils";
-import { parseRound } from "@/types/accounts";
+import { ParsedRound, parseRound } from "@/types/accounts";
 import { GetProgramAccountsFilter } from "@solana/web3.js";
 import { NextRequest, NextResponse } from "next/server";
 import { BN } from "@coral-xyz/anchor";
 
+// round accounts don't store their table, so it is checked through the round PDA
+function isTableRound({ publicKey, roundNumber }: ParsedRound) {
+  const pda = MAGIC_ROULETTE_CLIENT.getRoundPda(TABLE, new BN(roundNumber));
+
+  return pda.toBase58() === publicKey;
+}
+
 export async function GET(req: NextRequest) {
   const { searchParams } = new URL(req.url);
 
@@ -30,20 +37,22 @@ export async function GET(req: NextRequest) {
       if (isSpun) {
         filters.push({
           memcmp: {
-            offset: DISCRIMINATOR_SIZE + 8 + 8,
+            offset: DISCRIMINATOR_SIZE + 8 + 32 + 8,
             bytes: boolToByte(isSpun.toLowerCase() === "true"),
             encoding: "base64",
           },
         });
       }
 
+      const rounds = await MAGIC_ROULETTE_CLIENT.fetchAllProgramAccounts(
+        "round",
+        parseRound,
+        filters
+      );
+
       return NextResponse.json(
         {
-          rounds: await MAGIC_ROULETTE_CLIENT.fetchAllProgramAccounts(
-            "round",
-            parseRound,
-            filters
-          ),
+          rounds: rounds.filter(isTableRound),
         },
         {
           status: 200,
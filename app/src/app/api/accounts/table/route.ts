@@ -1,4 +1,4 @@
-import { MAGIC_ROULETTE_CLIENT } from "@/lib/server/solana";
+import { MAGIC_ROULETTE_CLIENT, TABLE } from "@/lib/server/solana";
 import { parseTable } from "@/types/accounts";
 import { NextResponse } from "next/server";
 
@@ -7,7 +7,7 @@ export async function GET() {
     return NextResponse.json(
       {
         table: await MAGIC_ROULETTE_CLIENT.fetchProgramAccount(
-          MAGIC_ROULETTE_CLIENT.getTablePda(),
+          TABLE,
           "table",
           parseTable
         ),
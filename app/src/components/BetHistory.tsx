@@ -40,7 +40,12 @@ import { useProgram } from "@/providers/ProgramProvider";
 import { buildTx } from "@/lib/client/solana";
 import { useRounds } from "@/providers/RoundsProvider";
 import { sendTx } from "@/lib/api";
-import { isWinner, payoutMultiplier } from "@/lib/betType";
+import {
+  hasWinningPosition,
+  isWinner,
+  payoutMultiplier,
+  positionsPayout,
+} from "@/lib/betType";
 import { cn, formatBetType, parseLamportsToSol } from "@/lib/utils";
 import {
   Table,
@@ -141,7 +146,7 @@ function SortIcon({ column }: { column: Column<BetHistoryRecord, unknown> }) {
 export function BetHistory() {
   const { connection } = useConnection();
   const { publicKey, signTransaction } = useUnifiedWallet();
-  const { magicRouletteClient } = useProgram();
+  const { magicRouletteClient, table } = useProgram();
   const { roundsData, roundsLoading } = useRounds();
   const { betsData, betsLoading, betsMutate } = useBets();
   const { getAccountLink, priorityFee } = useSettings();
@@ -168,7 +173,10 @@ export function BetHistory() {
         return false;
       }
 
-      return isWinner(bet.betType, matchingRound.outcome) && !bet.isClaimed;
+      return (
+        hasWinningPosition(bet.positions, matchingRound.outcome) &&
+        !bet.isClaimed
+      );
     });
   }, [betsData, roundsData]);
 
@@ -197,12 +205,14 @@ export function BetHistory() {
         return total;
       }
 
-      if (isWinner(bet.betType, matchingRound.outcome)) {
-        const payout = new BN(bet.amount).muln(payoutMultiplier(bet.betType));
-        return total.add(payout);
-      } else {
-        return total.sub(new BN(bet.amount));
-      }
+      return bet.positions.reduce((pnl, { betType, amount }) => {
+        if (isWinner(betType, matchingRound.outcome)) {
+          const payout = new BN(amount).muln(payoutMultiplier(betType));
+          return pnl.add(payout);
+        } else {
+          return pnl.sub(new BN(amount));
+        }
+      }, total);
     }, new BN(0));
   }, [roundsData, betsData]);
 
@@ -215,18 +225,23 @@ export function BetHistory() {
           (round) => round.publicKey === bet.round
         );
 
-        const hasWon = isWinner(bet.betType, matchingRound!.outcome);
+        const hasWon = hasWinningPosition(
+          bet.positions,
+          matchingRound!.outcome
+        );
 
         return {
           publicKey: bet.publicKey,
           round: matchingRound!.roundNumber,
           amount: parseLamportsToSol(bet.amount),
-          betType: formatBetType(bet.betType),
+          betType: bet.positions
+            .map(({ betType }) => formatBetType(betType))
+            .join(", "),
           outcome: matchingRound!.outcome!,
           hasWon,
           claimable: hasWon && !bet.isClaimed,
           payout: hasWon
-            ? parseBN(new BN(bet.amount).muln(payoutMultiplier(bet.betType)))
+            ? parseBN(positionsPayout(bet.positions, matchingRound!.outcome))
             : "",
         };
       })
@@ -385,6 +400,7 @@ export function BetHistory() {
           [
             await magicRouletteClient.claimWinningsIx({
               player: publicKey,
+              table,
               roundAndBets,
             }),
           ],
@@ -435,6 +451,7 @@ export function BetHistory() {
     betsData,
     connection,
     magicRouletteClient,
+    table,
     publicKey,
     roundsData,
     priorityFee,
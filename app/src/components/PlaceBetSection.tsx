@@ -29,7 +29,7 @@ export function PlaceBetSection() {
   const { connection } = useConnection();
   const { publicKey, signTransaction } = useUnifiedWallet();
   const { priorityFee } = useSettings();
-  const { magicRouletteClient } = useProgram();
+  const { magicRouletteClient, table } = useProgram();
   const { tableData } = useTable();
   const { currentRound, isRoundOver } = useRounds();
   const { betsData, betsMutate, selectedBet, formattedBet } = useBets();
@@ -74,6 +74,7 @@ export function PlaceBetSection() {
             [
               await magicRouletteClient.placeBetIx({
                 player: publicKey,
+                table,
                 betAmount: parseSolToLamports(betAmount),
                 betType: selectedBet,
               }),
@@ -110,10 +111,19 @@ export function PlaceBetSection() {
                 ...prev,
                 {
                   publicKey: magicRouletteClient
-                    .getBetPda(new PublicKey(currentRound.publicKey), publicKey)
+                    .getBetPda(
+                      table,
+                      new PublicKey(currentRound.publicKey),
+                      publicKey
+                    )
                     .toBase58(),
                   amount: amountInLamports,
-                  betType: selectedBet,
+                  positions: [
+                    {
+                      betType: selectedBet,
+                      amount: amountInLamports,
+                    },
+                  ],
                   isClaimed: false,
                   player: publicKey.toBase58(),
                   round: currentRound.publicKey,
@@ -134,6 +144,7 @@ export function PlaceBetSection() {
     [
       connection,
       magicRouletteClient,
+      table,
       priorityFee,
       publicKey,
       selectedBet,
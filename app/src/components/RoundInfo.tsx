@@ -40,7 +40,7 @@ function RoundInfoP({ text }: { text: string }) {
 }
 
 export function RoundInfo() {
-  const { magicRouletteClient } = useProgram();
+  const { magicRouletteClient, table } = useProgram();
   const { priorityFee, getAccountLink } = useSettings();
   const { connection } = useConnection();
   const { tableData, tableLoading } = useTable();
@@ -63,14 +63,14 @@ export function RoundInfo() {
     throw new Error("Table is not initialized.");
   }
 
-  const currentBetType = useMemo(() => {
+  const currentPositions = useMemo(() => {
     const betAcc = betsData?.find((bet) => {
       return bet.round === currentRound?.publicKey;
     });
 
     if (!betAcc) return null;
 
-    return betAcc.betType;
+    return betAcc.positions;
   }, [currentRound, betsData]);
 
   const spinRoulette = useCallback(() => {
@@ -83,9 +83,11 @@ export function RoundInfo() {
         setIsSendingTransaction(true);
 
         const currentRoundPda = magicRouletteClient.getRoundPda(
+          table,
           new BN(tableData.currentRoundNumber)
         );
         const newRoundPda = magicRouletteClient.getRoundPda(
+          table,
           new BN(tableData.currentRoundNumber).addn(1)
         );
 
@@ -94,6 +96,7 @@ export function RoundInfo() {
           [
             await magicRouletteClient.spinRouletteIx({
               payer: FUNDED_KEYPAIR_PUBKEY,
+              table,
               currentRound: currentRoundPda,
               newRound: newRoundPda,
             }),
@@ -146,6 +149,7 @@ export function RoundInfo() {
     currentRound,
     connection,
     magicRouletteClient,
+    table,
     priorityFee,
     setIsSendingTransaction,
     showTransactionToast,
@@ -206,6 +210,7 @@ export function RoundInfo() {
           onClick={() => {
             if (tableData && new BN(tableData.currentRoundNumber).gtn(1)) {
               const previousRoundData = magicRouletteClient.getRoundPda(
+                table,
                 new BN(tableData.currentRoundNumber).subn(1)
               );
               window.open(
@@ -227,9 +232,9 @@ export function RoundInfo() {
           <RoundInfoP text="Last Round Outcome" />
         </InfoDiv>
         <InfoDiv
-          className={cn(currentBetType ? "cursor-pointer" : "")}
+          className={cn(currentPositions ? "cursor-pointer" : "")}
           onClick={() => {
-            if (currentBetType && betsData && currentRound) {
+            if (currentPositions && betsData && currentRound) {
               const bet = betsData.find((bet) => {
                 return bet.round === currentRound.publicKey;
               });
@@ -247,7 +252,11 @@ export function RoundInfo() {
           ) : (
             <RoundInfoSpan
               text={
-                currentBetType !== null ? formatBetType(currentBetType) : "-"
+                currentPositions !== null
+                  ? currentPositions
+                      .map(({ betType }) => formatBetType(betType))
+                      .join(", ")
+                  : "-"
               }
             />
           )}
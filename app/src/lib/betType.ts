@@ -1,4 +1,5 @@
-import { BetType } from "@/types/accounts";
+import { BetType, ParsedPosition } from "@/types/accounts";
+import { BN } from "@coral-xyz/anchor";
 
 const RED_NUMBERS = [
   1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36,
@@ -31,6 +32,10 @@ export function isWinner(betType: BetType, outcome: number | null): boolean {
     return [0, 1, 2, 3, 37].includes(outcome);
   }
 
+  if (betType.topLine) {
+    return [0, 1, 2, 3].includes(outcome);
+  }
+
   if (betType.line) {
     return betType.line.numbers.includes(outcome);
   }
@@ -95,6 +100,7 @@ export function payoutMultiplier(betType: BetType): number {
   if ("street" in betType) return 11;
   if ("corner" in betType) return 8;
   if ("fiveNumber" in betType) return 6;
+  if ("topLine" in betType) return 8;
   if ("line" in betType) return 5;
   if ("column" in betType) return 2;
   if ("dozen" in betType) return 2;
@@ -107,3 +113,22 @@ export function payoutMultiplier(betType: BetType): number {
 
   throw new Error("Invalid bet type.");
 }
+
+// a bet ticket wins as soon as one of its positions covers the outcome
+export function hasWinningPosition(
+  positions: ParsedPosition[],
+  outcome: number | null
+): boolean {
+  return positions.some(({ betType }) => isWinner(betType, outcome));
+}
+
+export function positionsPayout(
+  positions: ParsedPosition[],
+  outcome: number | null
+): BN {
+  return positions.reduce((payout, { betType, amount }) => {
+    return isWinner(betType, outcome)
+      ? payout.add(new BN(amount).muln(payoutMultiplier(betType)))
+      : payout;
+  }, new BN(0));
+}
@@ -1 +1,4 @@
+import { BN } from "@coral-xyz/anchor";
+
 export const DISCRIMINATOR_SIZE = 8;
+export const TABLE_ID = new BN(process.env.NEXT_PUBLIC_TABLE_ID || 0);
@@ -12,30 +12,39 @@ export class MagicRouletteClient extends ProgramClient<MagicRoulette> {
     super(connection, magicRouletteIdl);
   }
 
-  getBetPda(round: PublicKey, player: PublicKey) {
+  getBetPda(table: PublicKey, round: PublicKey, player: PublicKey) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("bet"), round.toBuffer(), player.toBuffer()],
+      [
+        Buffer.from("bet"),
+        table.toBuffer(),
+        round.toBuffer(),
+        player.toBuffer(),
+      ],
       this.program.programId
     )[0];
   }
 
-  getTablePda() {
+  getTablePda(tableId: BN) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("table")],
+      [Buffer.from("table"), tableId.toArrayLike(Buffer, "le", 8)],
       this.program.programId
     )[0];
   }
 
-  getVaultPda() {
+  getVaultPda(table: PublicKey) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("vault")],
+      [Buffer.from("vault"), table.toBuffer()],
       this.program.programId
     )[0];
   }
 
-  getRoundPda(roundNumber: BN) {
+  getRoundPda(table: PublicKey, roundNumber: BN) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("round"), roundNumber.toArrayLike(Buffer, "le", 8)],
+      [
+        Buffer.from("round"),
+        table.toBuffer(),
+        roundNumber.toArrayLike(Buffer, "le", 8),
+      ],
       this.program.programId
     )[0];
   }
@@ -44,25 +53,30 @@ export class MagicRouletteClient extends ProgramClient<MagicRoulette> {
     betType,
     betAmount,
     player,
+    table,
   }: {
     betType: BetType;
     betAmount: bigIntString;
     player: Address;
+    table: Address;
   }): Promise<TransactionInstruction> {
     return await this.program.methods
       .placeBet(betType, new BN(betAmount))
-      .accounts({
+      .accountsPartial({
         player,
+        table,
       })
       .instruction();
   }
 
   async spinRouletteIx({
     payer,
+    table,
     currentRound,
     newRound,
   }: {
     payer: Address;
+    table: Address;
     currentRound: Address;
     newRound: Address;
   }): Promise<TransactionInstruction> {
@@ -70,6 +84,7 @@ export class MagicRouletteClient extends ProgramClient<MagicRoulette> {
       .spinRoulette()
       .accountsPartial({
         payer,
+        table,
         currentRound,
         newRound,
       })
@@ -78,15 +93,18 @@ export class MagicRouletteClient extends ProgramClient<MagicRoulette> {
 
   async claimWinningsIx({
     player,
+    table,
     roundAndBets,
   }: {
     player: Address;
+    table: Address;
     roundAndBets: { round: Address; bet: Address }[];
   }): Promise<TransactionInstruction> {
     return await this.program.methods
       .claimWinnings()
-      .accounts({
+      .accountsPartial({
         player,
+        table,
       })
       .remainingAccounts(
         roundAndBets.reduce<AccountMeta[]>((acc, { round, bet }) => {
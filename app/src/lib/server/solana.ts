@@ -13,7 +13,7 @@ import {
   CuPriceRange,
   SendTransactionResponse,
 } from "@/types/transactions";
-import { DISCRIMINATOR_SIZE } from "../constants";
+import { DISCRIMINATOR_SIZE, TABLE_ID } from "../constants";
 
 const CLUSTER: Cluster = (process.env.SOLANA_RPC_CLUSTER ??
   "devnet") as Cluster;
@@ -22,6 +22,7 @@ export const CONNECTION = new Connection(
   "confirmed"
 );
 export const MAGIC_ROULETTE_CLIENT = new MagicRouletteClient(CONNECTION);
+export const TABLE = MAGIC_ROULETTE_CLIENT.getTablePda(TABLE_ID);
 
 export const FUNDED_KEYPAIR = Keypair.fromSecretKey(
   new Uint8Array(JSON.parse(process.env.FUNDED_KEYPAIR as string))
@@ -138,6 +138,8 @@ export function formatBetType(betType: BetType): string {
     return `Corner: ${betType.corner?.numbers.join("-")}`;
   } else if ("fiveNumber" in betType) {
     return "Five Number";
+  } else if ("topLine" in betType) {
+    return "Top Line";
   } else if ("line" in betType) {
     return `Line: ${betType.line?.numbers.join("-")}`;
   } else if ("column" in betType) {
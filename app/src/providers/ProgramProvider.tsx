@@ -1,9 +1,12 @@
+import { TABLE_ID } from "@/lib/constants";
 import { MagicRouletteClient } from "@/lib/magic-roulette-client";
+import { PublicKey } from "@solana/web3.js";
 import { useConnection } from "@jup-ag/wallet-adapter";
 import { createContext, ReactNode, useContext, useMemo } from "react";
 
 interface ProgramContextType {
   magicRouletteClient: MagicRouletteClient;
+  table: PublicKey;
 }
 
 const ProgramContext = createContext<ProgramContextType>(
@@ -22,10 +25,16 @@ export function ProgramProvider({ children }: { children: ReactNode }) {
     [connection]
   );
 
+  const table = useMemo(
+    () => magicRouletteClient.getTablePda(TABLE_ID),
+    [magicRouletteClient]
+  );
+
   return (
     <ProgramContext.Provider
       value={{
         magicRouletteClient,
+        table,
       }}
     >
       {children}
@@ -19,7 +19,7 @@ import { useConnection, useUnifiedWallet } from "@jup-ag/wallet-adapter";
 import { parseLamportsToSol, timestampToMilli } from "@/lib/utils";
 import { useTime } from "./TimeProvider";
 import { useBets } from "./BetsProvider";
-import { isWinner, payoutMultiplier } from "@/lib/betType";
+import { hasWinningPosition, positionsPayout } from "@/lib/betType";
 import { toast } from "sonner";
 
 interface RoundsContextType {
@@ -77,7 +77,7 @@ export function RoundsProvider({
   );
   const { tableData, tableMutate } = useTable();
   const { betsData } = useBets();
-  const { magicRouletteClient } = useProgram();
+  const { magicRouletteClient, table } = useProgram();
   const { publicKey } = useUnifiedWallet();
   const { connection } = useConnection();
   const { time } = useTime();
@@ -147,11 +147,15 @@ export function RoundsProvider({
           });
 
           if (roundPlayerBet) {
-            const hasWon = isWinner(roundPlayerBet.betType, round.outcome);
+            const hasWon = hasWinningPosition(
+              roundPlayerBet.positions,
+              round.outcome
+            );
 
             if (hasWon) {
-              const amountWonInLamports = new BN(roundPlayerBet.amount).muln(
-                payoutMultiplier(roundPlayerBet.betType)
+              const amountWonInLamports = positionsPayout(
+                roundPlayerBet.positions,
+                round.outcome
               );
               const amountWonInSol = parseLamportsToSol(
                 amountWonInLamports.toString()
@@ -184,7 +188,10 @@ export function RoundsProvider({
           };
         });
 
-        const newRoundPda = magicRouletteClient.getRoundPda(newRoundNumber);
+        const newRoundPda = magicRouletteClient.getRoundPda(
+          table,
+          newRoundNumber
+        );
 
         await roundsMutate(
           (prev) => {
@@ -220,6 +227,7 @@ export function RoundsProvider({
     },
     [
       magicRouletteClient,
+      table,
       publicKey,
       betsData,
       currentRound,
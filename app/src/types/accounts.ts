@@ -17,6 +17,7 @@ type Table = IdlAccounts<MagicRoulette>["table"];
 export type Round = IdlAccounts<MagicRoulette>["round"];
 type Bet = IdlAccounts<MagicRoulette>["bet"];
 export type BetType = IdlTypes<MagicRoulette>["betType"];
+type Position = IdlTypes<MagicRoulette>["position"];
 
 export interface ParsedProgramAccount {
   publicKey: string;
@@ -37,11 +38,16 @@ export interface ParsedRound extends ParsedProgramAccount {
   outcome: Option<u8>;
 }
 
+export interface ParsedPosition {
+  betType: BetType;
+  amount: u64;
+}
+
 export interface ParsedBet extends ParsedProgramAccount {
   player: pubkey;
   round: pubkey;
   amount: u64;
-  betType: BetType;
+  positions: ParsedPosition[];
   isClaimed: boolean;
 }
 
@@ -89,16 +95,23 @@ export function parseRound({
   };
 }
 
+function parsePosition({ betType, amount }: Position): ParsedPosition {
+  return {
+    betType,
+    amount: parseBN(amount),
+  };
+}
+
 export function parseBet({
   amount,
-  betType,
+  positions,
   player,
   round,
   isClaimed,
 }: Bet): Omit<ParsedBet, "publicKey"> {
   return {
     amount: parseBN(amount),
-    betType,
+    positions: positions.map(parsePosition),
     player: parsePublicKey(player),
     round: parsePublicKey(round),
     isClaimed,
@@ -30,6 +30,11 @@ export type MagicRoulette = {
               {
                 kind: "const";
                 value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
               }
             ];
           };
@@ -45,8 +50,12 @@ export type MagicRoulette = {
               },
               {
                 kind: "account";
-                path: "table.current_round_number";
-                account: "table";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "current_round.round_number";
+                account: "round";
               }
             ];
           };
@@ -62,8 +71,28 @@ export type MagicRoulette = {
               },
               {
                 kind: "account";
-                path: "table.current_round_number.add(1)";
-                account: "table";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "current_round.round_number.add(1)";
+                account: "round";
+              }
+            ];
+          };
+        },
+        {
+          name: "history";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [104, 105, 115, 116, 111, 114, 121];
+              },
+              {
+                kind: "account";
+                path: "table";
               }
             ];
           };
@@ -79,93 +108,139 @@ export type MagicRoulette = {
           type: {
             array: ["u8", 32];
           };
+        },
+        {
+          name: "callerSeed";
+          type: {
+            array: ["u8", 32];
+          };
         }
       ];
     },
     {
-      name: "claimWinnings";
-      discriminator: [161, 215, 24, 59, 14, 236, 242, 221];
+      name: "carryImprisonedStake";
+      discriminator: [128, 245, 130, 13, 26, 69, 137, 3];
       accounts: [
         {
-          name: "player";
+          name: "payer";
+          docs: [
+            "Anyone can carry imprisoned stakes, pays the rent of the next bet if it has to be created"
+          ];
           writable: true;
           signer: true;
         },
         {
-          name: "vault";
+          name: "player";
+          docs: ["Player who placed the bet, receives the rent back"];
+          writable: true;
+        },
+        {
+          name: "table";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [118, 97, 117, 108, 116];
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
               }
             ];
           };
         },
         {
-          name: "table";
+          name: "round";
+          docs: ["Round in which the stake went en prison"];
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 97, 98, 108, 101];
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round.round_number";
+                account: "round";
               }
             ];
           };
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "initializeTable";
-      discriminator: [223, 143, 246, 102, 122, 200, 108, 147];
-      accounts: [
-        {
-          name: "admin";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "vault";
+          name: "nextRound";
+          docs: [
+            "Open round of the table, whose spin decides whether the imprisoned stake is returned"
+          ];
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [118, 97, 117, 108, 116];
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
               }
             ];
           };
         },
         {
-          name: "table";
+          name: "bet";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 97, 98, 108, 101];
+                value: [98, 101, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round";
+              },
+              {
+                kind: "account";
+                path: "player";
               }
             ];
           };
         },
         {
-          name: "round";
+          name: "nextBet";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [114, 111, 117, 110, 100];
+                value: [98, 101, 116];
               },
               {
-                kind: "const";
-                value: [1, 0, 0, 0, 0, 0, 0, 0];
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "nextRound";
+              },
+              {
+                kind: "account";
+                path: "player";
               }
             ];
           };
@@ -175,183 +250,294 @@ export type MagicRoulette = {
           address: "11111111111111111111111111111111";
         }
       ];
-      args: [
-        {
-          name: "minimumBetAmount";
-          type: "u64";
-        },
-        {
-          name: "roundPeriodTs";
-          type: "u64";
-        }
-      ];
+      args: [];
     },
     {
-      name: "placeBet";
-      discriminator: [222, 62, 67, 220, 63, 166, 126, 33];
+      name: "claimReferralRewards";
+      discriminator: [23, 112, 76, 162, 157, 106, 203, 246];
       accounts: [
         {
-          name: "player";
+          name: "referrer";
           writable: true;
           signer: true;
         },
         {
-          name: "vault";
+          name: "feeVault";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [118, 97, 117, 108, 116];
+                value: [102, 101, 101, 95, 118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
               }
             ];
           };
         },
         {
           name: "table";
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
               }
             ];
           };
         },
         {
-          name: "round";
+          name: "referral";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [114, 111, 117, 110, 100];
+                value: [114, 101, 102, 101, 114, 114, 97, 108];
               },
               {
                 kind: "account";
-                path: "table.current_round_number";
-                account: "table";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "referrer";
               }
             ];
           };
         },
         {
-          name: "bet";
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "feeVaultTokenAccount";
           writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [98, 101, 116];
+                kind: "account";
+                path: "feeVault";
               },
               {
                 kind: "account";
-                path: "round";
+                path: "tokenProgram";
               },
               {
                 kind: "account";
-                path: "player";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [
+          name: "referrerTokenAccount";
+          writable: true;
+          optional: true;
+        },
         {
-          name: "betType";
-          type: {
-            defined: {
-              name: "betType";
-            };
-          };
+          name: "tokenProgram";
+          optional: true;
         },
         {
-          name: "betAmount";
-          type: "u64";
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
         }
       ];
+      args: [];
     },
     {
-      name: "spinRoulette";
-      discriminator: [6, 130, 248, 38, 161, 155, 17, 30];
+      name: "claimWinnings";
+      discriminator: [161, 215, 24, 59, 14, 236, 242, 221];
       accounts: [
         {
-          name: "payer";
+          name: "player";
           writable: true;
           signer: true;
         },
         {
-          name: "table";
+          name: "vault";
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 97, 98, 108, 101];
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
               }
             ];
           };
         },
         {
-          name: "currentRound";
+          name: "table";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [114, 111, 117, 110, 100];
+                value: [116, 97, 98, 108, 101];
               },
               {
                 kind: "account";
-                path: "table.current_round_number";
+                path: "table.table_id";
                 account: "table";
               }
             ];
           };
         },
         {
-          name: "newRound";
+          name: "playerStats";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [114, 111, 117, 110, 100];
+                value: [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ];
               },
               {
                 kind: "account";
-                path: "table.current_round_number.add(1)";
+                path: "player";
+              },
+              {
+                kind: "account";
+                path: "table.mint";
                 account: "table";
               }
             ];
           };
         },
         {
-          name: "oracleQueue";
-          writable: true;
-          address: "Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh";
+          name: "mint";
+          optional: true;
         },
         {
-          name: "programIdentity";
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [105, 100, 101, 110, 116, 105, 116, 121];
+                kind: "account";
+                path: "vault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
         {
-          name: "vrfProgram";
-          address: "Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz";
+          name: "playerTokenAccount";
+          writable: true;
+          optional: true;
         },
         {
-          name: "slotHashes";
-          address: "SysvarS1otHashes111111111111111111111111111";
+          name: "tokenProgram";
+          optional: true;
         },
         {
           name: "systemProgram";
@@ -361,82 +547,366 @@ export type MagicRoulette = {
       args: [];
     },
     {
-      name: "updateTable";
-      discriminator: [224, 23, 10, 48, 181, 73, 121, 187];
+      name: "closeLosingBet";
+      discriminator: [80, 132, 195, 35, 207, 61, 209, 137];
       accounts: [
         {
-          name: "admin";
+          name: "player";
+          docs: ["Player who placed the bet, receives the rent back"];
           writable: true;
-          signer: true;
-          relations: ["table"];
         },
         {
           name: "table";
-          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
               }
             ];
           };
-        }
-      ];
-      args: [
-        {
-          name: "minimumBetAmount";
-          type: {
-            option: "u64";
-          };
-        },
-        {
-          name: "roundPeriodTs";
-          type: {
-            option: "u64";
-          };
-        },
-        {
-          name: "newAdmin";
-          type: {
-            option: "pubkey";
-          };
-        }
-      ];
-    },
-    {
-      name: "withdrawVault";
-      discriminator: [135, 7, 237, 120, 149, 94, 95, 7];
-      accounts: [
-        {
-          name: "admin";
-          writable: true;
-          signer: true;
-          relations: ["table"];
         },
         {
-          name: "vault";
+          name: "round";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [118, 97, 117, 108, 116];
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round.round_number";
+                account: "round";
+              }
+            ];
+          };
+        },
+        {
+          name: "bet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [98, 101, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round";
+              },
+              {
+                kind: "account";
+                path: "player";
+              }
+            ];
+          };
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeRound";
+      discriminator: [149, 14, 81, 88, 230, 226, 234, 37];
+      accounts: [
+        {
+          name: "payer";
+          docs: ["Account that paid for the round, receives the rent back"];
+          writable: true;
+        },
+        {
+          name: "table";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "round";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round.round_number";
+                account: "round";
               }
             ];
           };
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializeTable";
+      discriminator: [223, 143, 246, 102, 122, 200, 108, 147];
+      accounts: [
+        {
+          name: "admin";
+          writable: true;
+          signer: true;
         },
         {
           name: "table";
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "arg";
+                path: "tableId";
+              }
+            ];
+          };
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "feeVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [102, 101, 101, 95, 118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "round";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "const";
+                value: [1, 0, 0, 0, 0, 0, 0, 0];
+              }
+            ];
+          };
+        },
+        {
+          name: "history";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [104, 105, 115, 116, 111, 114, 121];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          docs: [
+            "Mint bets are placed in, the table uses lamports when not provided"
+          ];
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          docs: [
+            "Vault token account for holding round bet amounts of token-denominated tables"
+          ];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "vault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "feeVaultTokenAccount";
+          docs: [
+            "Fee vault token account for holding the rake of token-denominated tables"
+          ];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "feeVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
+        {
+          name: "adminTokenAccount";
+          docs: [
+            "Admin token account the initial bankroll of token-denominated tables is drawn from"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "associatedTokenProgram";
+          optional: true;
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
@@ -444,343 +914,3303 @@ export type MagicRoulette = {
       ];
       args: [
         {
-          name: "amount";
+          name: "tableId";
+          type: "u64";
+        },
+        {
+          name: "args";
           type: {
-            option: "u64";
+            defined: {
+              name: "initializeTableArgs";
+            };
           };
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "bet";
-      discriminator: [147, 23, 35, 59, 15, 75, 155, 32];
-    },
-    {
-      name: "round";
-      discriminator: [87, 127, 165, 51, 73, 78, 116, 174];
-    },
-    {
-      name: "table";
-      discriminator: [34, 100, 138, 97, 236, 129, 230, 112];
-    }
-  ];
-  events: [
-    {
-      name: "betPlaced";
-      discriminator: [88, 88, 145, 226, 126, 206, 32, 0];
-    },
-    {
-      name: "rouletteSpun";
-      discriminator: [7, 20, 158, 171, 79, 107, 76, 53];
-    },
-    {
-      name: "roundAdvanced";
-      discriminator: [215, 177, 143, 241, 202, 145, 181, 213];
-    },
-    {
-      name: "tableUpdated";
-      discriminator: [144, 69, 221, 154, 241, 242, 29, 70];
-    },
-    {
-      name: "vaultWithdrawn";
-      discriminator: [238, 9, 219, 172, 188, 77, 72, 104];
-    },
-    {
-      name: "winningsClaimed";
-      discriminator: [187, 184, 29, 196, 54, 117, 70, 150];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "invalidQueue";
-      msg: "Oracle queue does not match";
-    },
-    {
-      code: 6001;
-      name: "invalidBetAmount";
-      msg: "Bet below minimum bet amount";
-    },
-    {
-      code: 6002;
-      name: "mathOverflow";
-      msg: "Math overflow";
     },
     {
-      code: 6003;
+      name: "lpDeposit";
+      discriminator: [27, 77, 210, 69, 12, 43, 148, 16];
+      accounts: [
+        {
+          name: "provider";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "currentRound";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "lpPosition";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [108, 112, 95, 112, 111, 115, 105, 116, 105, 111, 110];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "provider";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "lpWithdraw";
+      discriminator: [205, 206, 130, 170, 173, 51, 11, 169];
+      accounts: [
+        {
+          name: "provider";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "currentRound";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "lpPosition";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [108, 112, 95, 112, 111, 115, 105, 116, 105, 111, 110];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "provider";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "shares";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "placeBet";
+      discriminator: [222, 62, 67, 220, 63, 166, 126, 33];
+      accounts: [
+        {
+          name: "player";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "feeVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [102, 101, 101, 95, 118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "round";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "bet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [98, 101, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round";
+              },
+              {
+                kind: "account";
+                path: "player";
+              }
+            ];
+          };
+        },
+        {
+          name: "playerStats";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ];
+              },
+              {
+                kind: "account";
+                path: "player";
+              },
+              {
+                kind: "account";
+                path: "table.mint";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "referral";
+          docs: ["Referral of the account that referred the player, if any"];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 101, 102, 101, 114, 114, 97, 108];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "referral.referrer";
+                account: "referral";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "vault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "feeVaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "feeVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "playerTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "betType";
+          type: {
+            defined: {
+              name: "betType";
+            };
+          };
+        },
+        {
+          name: "betAmount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "placeBets";
+      discriminator: [49, 131, 14, 212, 212, 143, 224, 150];
+      accounts: [
+        {
+          name: "player";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "feeVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [102, 101, 101, 95, 118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "round";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "bet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [98, 101, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round";
+              },
+              {
+                kind: "account";
+                path: "player";
+              }
+            ];
+          };
+        },
+        {
+          name: "playerStats";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ];
+              },
+              {
+                kind: "account";
+                path: "player";
+              },
+              {
+                kind: "account";
+                path: "table.mint";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "referral";
+          docs: ["Referral of the account that referred the player, if any"];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 101, 102, 101, 114, 114, 97, 108];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "referral.referrer";
+                account: "referral";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "vault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "feeVaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "feeVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "playerTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "positions";
+          type: {
+            vec: {
+              defined: {
+                name: "position";
+              };
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "registerReferral";
+      discriminator: [158, 196, 134, 102, 193, 102, 184, 86];
+      accounts: [
+        {
+          name: "referrer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "table";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "referral";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 101, 102, 101, 114, 114, 97, 108];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "referrer";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "retrySpin";
+      discriminator: [44, 230, 235, 37, 139, 14, 253, 194];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "table";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "currentRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "newRound";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number.add(1)";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "history";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [104, 105, 115, 116, 111, 114, 121];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "oracleQueue";
+          writable: true;
+        },
+        {
+          name: "programIdentity";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [105, 100, 101, 110, 116, 105, 116, 121];
+              }
+            ];
+          };
+        },
+        {
+          name: "vrfProgram";
+          address: "Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz";
+        },
+        {
+          name: "slotHashes";
+          address: "SysvarS1otHashes111111111111111111111111111";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "settleBets";
+      discriminator: [230, 236, 14, 198, 123, 178, 98, 220];
+      accounts: [
+        {
+          name: "cranker";
+          docs: [
+            "Anyone can crank settlement, receives the crank fee of the table"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "round";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round.round_number";
+                account: "round";
+              }
+            ];
+          };
+        },
+        {
+          name: "currentRound";
+          docs: [
+            "Open round of the table, whose worst outcome the vault must keep covering"
+          ];
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "vault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "crankerTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "spinRoulette";
+      discriminator: [6, 130, 248, 38, 161, 155, 17, 30];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "table";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "currentRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "newRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number.add(1)";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "history";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [104, 105, 115, 116, 111, 114, 121];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "oracleQueue";
+          writable: true;
+        },
+        {
+          name: "programIdentity";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [105, 100, 101, 110, 116, 105, 116, 121];
+              }
+            ];
+          };
+        },
+        {
+          name: "vrfProgram";
+          address: "Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz";
+        },
+        {
+          name: "slotHashes";
+          address: "SysvarS1otHashes111111111111111111111111111";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "sweepExpired";
+      discriminator: [10, 72, 70, 57, 62, 128, 19, 22];
+      accounts: [
+        {
+          name: "admin";
+          signer: true;
+          relations: ["table"];
+        },
+        {
+          name: "player";
+          docs: ["Player who placed the bet, receives the rent back"];
+          writable: true;
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "round";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round.round_number";
+                account: "round";
+              }
+            ];
+          };
+        },
+        {
+          name: "bet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [98, 101, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "round";
+              },
+              {
+                kind: "account";
+                path: "player";
+              }
+            ];
+          };
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateTable";
+      discriminator: [224, 23, 10, 48, 181, 73, 121, 187];
+      accounts: [
+        {
+          name: "admin";
+          writable: true;
+          signer: true;
+          relations: ["table"];
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "updateTableArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "withdrawFees";
+      discriminator: [198, 212, 171, 109, 144, 215, 174, 89];
+      accounts: [
+        {
+          name: "admin";
+          writable: true;
+          signer: true;
+          relations: ["table"];
+        },
+        {
+          name: "feeVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [102, 101, 101, 95, 118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "table";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "feeVaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "feeVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "adminTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: {
+            option: "u64";
+          };
+        }
+      ];
+    },
+    {
+      name: "withdrawVault";
+      discriminator: [135, 7, 237, 120, 149, 94, 95, 7];
+      accounts: [
+        {
+          name: "admin";
+          writable: true;
+          signer: true;
+          relations: ["table"];
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "table";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 97, 98, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "table.table_id";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "currentRound";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 111, 117, 110, 100];
+              },
+              {
+                kind: "account";
+                path: "table";
+              },
+              {
+                kind: "account";
+                path: "table.current_round_number";
+                account: "table";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "vault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "adminTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: {
+            option: "u64";
+          };
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "bet";
+      discriminator: [147, 23, 35, 59, 15, 75, 155, 32];
+    },
+    {
+      name: "history";
+      discriminator: [31, 216, 60, 33, 213, 209, 70, 101];
+    },
+    {
+      name: "lpPosition";
+      discriminator: [105, 241, 37, 200, 224, 2, 252, 90];
+    },
+    {
+      name: "playerStats";
+      discriminator: [169, 146, 242, 176, 102, 118, 231, 172];
+    },
+    {
+      name: "referral";
+      discriminator: [30, 235, 136, 224, 106, 107, 49, 64];
+    },
+    {
+      name: "round";
+      discriminator: [87, 127, 165, 51, 73, 78, 116, 174];
+    },
+    {
+      name: "table";
+      discriminator: [34, 100, 138, 97, 236, 129, 230, 112];
+    }
+  ];
+  events: [
+    {
+      name: "betClosed";
+      discriminator: [122, 20, 157, 254, 221, 0, 39, 129];
+    },
+    {
+      name: "betPlaced";
+      discriminator: [88, 88, 145, 226, 126, 206, 32, 0];
+    },
+    {
+      name: "betsSettled";
+      discriminator: [175, 33, 212, 218, 55, 132, 159, 93];
+    },
+    {
+      name: "feesWithdrawn";
+      discriminator: [234, 15, 0, 119, 148, 241, 40, 21];
+    },
+    {
+      name: "imprisonedStakeCarried";
+      discriminator: [186, 8, 84, 158, 26, 243, 160, 123];
+    },
+    {
+      name: "lpDeposited";
+      discriminator: [85, 211, 184, 159, 176, 224, 28, 72];
+    },
+    {
+      name: "lpWithdrawn";
+      discriminator: [188, 10, 43, 60, 223, 238, 51, 153];
+    },
+    {
+      name: "referralRewardsClaimed";
+      discriminator: [178, 107, 76, 169, 252, 154, 45, 235];
+    },
+    {
+      name: "rouletteSpun";
+      discriminator: [7, 20, 158, 171, 79, 107, 76, 53];
+    },
+    {
+      name: "roundAdvanced";
+      discriminator: [215, 177, 143, 241, 202, 145, 181, 213];
+    },
+    {
+      name: "roundClosed";
+      discriminator: [45, 243, 28, 22, 132, 70, 175, 226];
+    },
+    {
+      name: "spinRetried";
+      discriminator: [236, 194, 193, 174, 249, 157, 3, 140];
+    },
+    {
+      name: "tableUpdated";
+      discriminator: [144, 69, 221, 154, 241, 242, 29, 70];
+    },
+    {
+      name: "vaultWithdrawn";
+      discriminator: [238, 9, 219, 172, 188, 77, 72, 104];
+    },
+    {
+      name: "winningsClaimed";
+      discriminator: [187, 184, 29, 196, 54, 117, 70, 150];
+    },
+    {
+      name: "winningsSwept";
+      discriminator: [231, 74, 131, 164, 212, 39, 234, 201];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "invalidQueue";
+      msg: "Oracle queue does not match";
+    },
+    {
+      code: 6001;
+      name: "invalidBetAmount";
+      msg: "Bet below minimum bet amount";
+    },
+    {
+      code: 6002;
+      name: "mathOverflow";
+      msg: "Math overflow";
+    },
+    {
+      code: 6003;
       name: "invalidPlayer";
       msg: "Player does not match";
     },
     {
-      code: 6004;
-      name: "invalidRound";
-      msg: "Round does not match";
+      code: 6004;
+      name: "invalidRound";
+      msg: "Round does not match";
+    },
+    {
+      code: 6005;
+      name: "roundNotReadyToSpin";
+      msg: "Round has not finished yet";
+    },
+    {
+      code: 6006;
+      name: "invalidRandomness";
+      msg: "Unable to map randomness to valid BetType";
+    },
+    {
+      code: 6007;
+      name: "insufficientRemainingAccounts";
+      msg: "Length of remaining accounts must be even";
+    },
+    {
+      code: 6008;
+      name: "invalidBet";
+      msg: "Bet does not match";
+    },
+    {
+      code: 6009;
+      name: "betNotWinning";
+      msg: "Bet is not a winning bet";
+    },
+    {
+      code: 6010;
+      name: "invalidMinimumBetAmount";
+      msg: "Minimum bet amount must be greater than zero";
+    },
+    {
+      code: 6011;
+      name: "invalidRoundPeriod";
+      msg: "Round period must be greater than zero";
+    },
+    {
+      code: 6012;
+      name: "unauthorizedAdmin";
+      msg: "Admin does not match the one in table";
+    },
+    {
+      code: 6013;
+      name: "roundOver";
+      msg: "Round is no longer accepting bets";
+    },
+    {
+      code: 6014;
+      name: "roundAwaitingOutcome";
+      msg: "Round outcome is not yet available";
+    },
+    {
+      code: 6015;
+      name: "insufficientVaultFunds";
+      msg: "Vault has insufficient funds to pay out winnings";
+    },
+    {
+      code: 6016;
+      name: "invalidBetType";
+      msg: "Bet type is illegal";
+    },
+    {
+      code: 6017;
+      name: "winningsAlreadyClaimed";
+      msg: "Winnings have already been claimed for this bet";
+    },
+    {
+      code: 6018;
+      name: "invalidAddress";
+      msg: "Address cannot be default pubkey";
+    },
+    {
+      code: 6019;
+      name: "vaultNotWithdrawable";
+      msg: "Vault does not have enough funds for withdrawal";
+    },
+    {
+      code: 6020;
+      name: "tooManyPositions";
+      msg: "Bet has reached the maximum number of positions";
+    },
+    {
+      code: 6021;
+      name: "noPositions";
+      msg: "At least one position must be placed";
+    },
+    {
+      code: 6022;
+      name: "invalidSpinTimeout";
+      msg: "Spin timeout must be greater than zero";
+    },
+    {
+      code: 6023;
+      name: "roundNotSpun";
+      msg: "Round has not been spun yet";
+    },
+    {
+      code: 6024;
+      name: "spinNotTimedOut";
+      msg: "Randomness request has not timed out yet";
+    },
+    {
+      code: 6025;
+      name: "invalidPayoutMultipliers";
+      msg: "Payout multipliers must be greater than zero and not exceed true odds";
+    },
+    {
+      code: 6026;
+      name: "enPrisonDisabled";
+      msg: "Round does not hold even-money bets en prison";
+    },
+    {
+      code: 6027;
+      name: "noImprisonedStake";
+      msg: "Bet has no stake held en prison";
+    },
+    {
+      code: 6028;
+      name: "stakeAlreadyCarried";
+      msg: "Imprisoned stake has already been carried into a later round";
+    },
+    {
+      code: 6029;
+      name: "invalidMaximumBetAmount";
+      msg: "Maximum bet amount must not be below the minimum bet amount";
+    },
+    {
+      code: 6030;
+      name: "invalidMaximumRoundLiability";
+      msg: "Maximum round liability must be greater than zero";
+    },
+    {
+      code: 6031;
+      name: "betAboveMaximum";
+      msg: "Bet above maximum bet amount";
+    },
+    {
+      code: 6032;
+      name: "roundLiabilityExceeded";
+      msg: "Bet would exceed the maximum round liability";
+    },
+    {
+      code: 6033;
+      name: "roundInsolvent";
+      msg: "Vault cannot cover the worst-case payout of the round";
+    },
+    {
+      code: 6034;
+      name: "betNotLosing";
+      msg: "Bet still has winnings or refunds to claim";
+    },
+    {
+      code: 6035;
+      name: "invalidClaimWindow";
+      msg: "Claim window must be greater than zero";
+    },
+    {
+      code: 6036;
+      name: "roundNotSettled";
+      msg: "Round still has bets to settle";
+    },
+    {
+      code: 6037;
+      name: "claimWindowExpired";
+      msg: "Claim window of the round has expired";
+    },
+    {
+      code: 6038;
+      name: "claimWindowOpen";
+      msg: "Claim window of the round has not expired yet";
+    },
+    {
+      code: 6039;
+      name: "liabilityUnderflow";
+      msg: "Amount released exceeds the unclaimed liability of the table";
+    },
+    {
+      code: 6040;
+      name: "invalidCrankFee";
+      msg: "Crank fee must not exceed the maximum crank fee";
+    },
+    {
+      code: 6041;
+      name: "invalidMint";
+      msg: "Mint does not match the mint of the table";
+    },
+    {
+      code: 6042;
+      name: "missingTokenAccount";
+      msg: "Token accounts are required for token-denominated tables";
+    },
+    {
+      code: 6043;
+      name: "unsupportedMint";
+      msg: "Mint has unsupported Token-2022 extensions";
+    },
+    {
+      code: 6044;
+      name: "lpTokenTableUnsupported";
+      msg: "Liquidity pool only supports lamport-denominated tables";
+    },
+    {
+      code: 6045;
+      name: "invalidAmount";
+      msg: "Amount must be greater than zero";
+    },
+    {
+      code: 6046;
+      name: "bankrollDepleted";
+      msg: "Bankroll is depleted, shares can't be priced";
+    },
+    {
+      code: 6047;
+      name: "insufficientShares";
+      msg: "Not enough shares for withdrawal";
+    },
+    {
+      code: 6048;
+      name: "lpCooldownActive";
+      msg: "Deposit is still in cooldown";
+    },
+    {
+      code: 6049;
+      name: "houseBankrollTooLow";
+      msg: "House bankroll is too low for liquidity providers to join";
+    },
+    {
+      code: 6050;
+      name: "invalidRakeBps";
+      msg: "Rake must not exceed the maximum rake";
+    },
+    {
+      code: 6051;
+      name: "invalidReferralBps";
+      msg: "Referral share must not exceed the rake";
+    },
+    {
+      code: 6052;
+      name: "selfReferral";
+      msg: "Players cannot refer themselves";
+    },
+    {
+      code: 6053;
+      name: "noReferralRewards";
+      msg: "No referral rewards to claim";
+    },
+    {
+      code: 6054;
+      name: "roundAlreadySpun";
+      msg: "Round has already been spun";
+    },
+    {
+      code: 6055;
+      name: "roundAlreadySettled";
+      msg: "Round has already been settled";
+    },
+    {
+      code: 6056;
+      name: "roundNotCurrent";
+      msg: "Round is not the current round of the table";
+    },
+    {
+      code: 6057;
+      name: "staleRandomnessRequest";
+      msg: "Randomness does not answer the latest request of the round";
+    }
+  ];
+  types: [
+    {
+      name: "bet";
+      docs: ["Ticket holding every position a player has placed in a round."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "player";
+            docs: ["Player who placed the bet."];
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            docs: ["Round in which the bet was placed."];
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            docs: ["Total amount of lamports wagered across all positions."];
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "isClaimed";
+            docs: [
+              "Boolean that indicates if the prize for a winning bet has been claimed."
+            ];
+            type: "bool";
+          },
+          {
+            name: "isCarried";
+            docs: [
+              "Boolean that indicates if stakes held en prison have been carried into a later round."
+            ];
+            type: "bool";
+          },
+          {
+            name: "positions";
+            docs: [
+              "Positions placed in the round, up to `Bet::MAX_POSITIONS`."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "position";
+                };
+              };
+            };
+          },
+          {
+            name: "imprisonedPositions";
+            docs: [
+              "Even-money stakes held en prison in earlier rounds and carried into this one, one per bet type."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "position";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "betClosed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "player";
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "betPlaced";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "player";
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "betType";
+            type: {
+              defined: {
+                name: "betType";
+              };
+            };
+          },
+          {
+            name: "betAmount";
+            type: "u64";
+          },
+          {
+            name: "rake";
+            docs: ["Rake charged on the bet, paid into the fee vault."];
+            type: "u64";
+          },
+          {
+            name: "referrer";
+            docs: ["Referrer credited with a share of the bet, if any."];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "betType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "straightUp";
+            fields: [
+              {
+                name: "number";
+                type: "u8";
+              }
+            ];
+          },
+          {
+            name: "split";
+            fields: [
+              {
+                name: "numbers";
+                type: {
+                  array: ["u8", 2];
+                };
+              }
+            ];
+          },
+          {
+            name: "street";
+            fields: [
+              {
+                name: "numbers";
+                type: {
+                  array: ["u8", 3];
+                };
+              }
+            ];
+          },
+          {
+            name: "corner";
+            fields: [
+              {
+                name: "numbers";
+                type: {
+                  array: ["u8", 4];
+                };
+              }
+            ];
+          },
+          {
+            name: "fiveNumber";
+          },
+          {
+            name: "line";
+            fields: [
+              {
+                name: "numbers";
+                type: {
+                  array: ["u8", 6];
+                };
+              }
+            ];
+          },
+          {
+            name: "column";
+            fields: [
+              {
+                name: "column";
+                type: "u8";
+              }
+            ];
+          },
+          {
+            name: "dozen";
+            fields: [
+              {
+                name: "dozen";
+                type: "u8";
+              }
+            ];
+          },
+          {
+            name: "red";
+          },
+          {
+            name: "black";
+          },
+          {
+            name: "even";
+          },
+          {
+            name: "odd";
+          },
+          {
+            name: "high";
+          },
+          {
+            name: "low";
+          },
+          {
+            name: "topLine";
+          }
+        ];
+      };
+    },
+    {
+      name: "betsSettled";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "cranker";
+            type: "pubkey";
+          },
+          {
+            name: "settledCount";
+            docs: ["Amount of bets settled in the batch."];
+            type: "u64";
+          },
+          {
+            name: "winnings";
+            docs: ["Winnings paid out to players."];
+            type: "u64";
+          },
+          {
+            name: "crankFee";
+            docs: ["Lamports paid to the cranker."];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "feesWithdrawn";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6005;
-      name: "roundNotReadyToSpin";
-      msg: "Round has not finished yet";
+      name: "history";
+      docs: [
+        "Outcomes of the latest spins of a table, kept in a ring buffer so frontends can read them with one fetch."
+      ];
+      serialization: "bytemuck";
+      repr: {
+        kind: "c";
+      };
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            docs: ["Table the spins belong to."];
+            type: "pubkey";
+          },
+          {
+            name: "count";
+            docs: [
+              "Amount of spins recorded since the table was initialized.",
+              "",
+              "The next spin is written at `count % History::CAPACITY`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "entries";
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: "historyEntry";
+                  };
+                },
+                256
+              ];
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "padding";
+            type: {
+              array: ["u8", 7];
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6006;
-      name: "invalidRandomness";
-      msg: "Unable to map randomness to valid BetType";
+      name: "historyEntry";
+      serialization: "bytemuck";
+      repr: {
+        kind: "c";
+      };
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "roundNumber";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            docs: ["Timestamp when the round was settled."];
+            type: "i64";
+          },
+          {
+            name: "outcome";
+            docs: ["The number that won (0-36, with 37 representing 00)"];
+            type: "u8";
+          },
+          {
+            name: "padding";
+            type: {
+              array: ["u8", 7];
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6007;
-      name: "insufficientRemainingAccounts";
-      msg: "Length of remaining accounts must be even";
+      name: "imprisonedStakeCarried";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "player";
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            docs: ["Round in which the stake went en prison."];
+            type: "pubkey";
+          },
+          {
+            name: "nextRound";
+            docs: ["Round the stake was carried into."];
+            type: "pubkey";
+          },
+          {
+            name: "stake";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6008;
-      name: "invalidBet";
-      msg: "Bet does not match";
+      name: "initializeTableArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "minimumBetAmount";
+            type: "u64";
+          },
+          {
+            name: "roundPeriodTs";
+            type: "u64";
+          },
+          {
+            name: "spinTimeoutTs";
+            type: "u64";
+          },
+          {
+            name: "claimWindowSecs";
+            type: "u64";
+          },
+          {
+            name: "oracleQueue";
+            type: "pubkey";
+          },
+          {
+            name: "wheel";
+            type: {
+              defined: {
+                name: "wheel";
+              };
+            };
+          },
+          {
+            name: "zeroRule";
+            type: {
+              defined: {
+                name: "zeroRule";
+              };
+            };
+          },
+          {
+            name: "initialVaultAmount";
+            docs: [
+              "Bankroll the admin deposits into the vault, in lamports or tokens of the mint"
+            ];
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6009;
-      name: "betNotWinning";
-      msg: "Bet is not a winning bet";
+      name: "lpDeposited";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "provider";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "shares";
+            docs: ["Shares minted for the deposit."];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6010;
-      name: "invalidMinimumBetAmount";
-      msg: "Minimum bet amount must be greater than zero";
+      name: "lpPosition";
+      docs: ["Share of the bankroll of a table held by a liquidity provider."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "provider";
+            docs: ["Liquidity provider owning the shares."];
+            type: "pubkey";
+          },
+          {
+            name: "shares";
+            docs: ["Amount of bankroll shares held."];
+            type: "u64";
+          },
+          {
+            name: "lastDepositTs";
+            docs: [
+              "Timestamp of the latest deposit, withdrawals open once the table cooldown has passed."
+            ];
+            type: "i64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6011;
-      name: "invalidRoundPeriod";
-      msg: "Round period must be greater than zero";
+      name: "lpWithdrawn";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "provider";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "shares";
+            docs: ["Shares burned for the withdrawal."];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6012;
-      name: "unauthorizedAdmin";
-      msg: "Admin does not match the one in table";
+      name: "playerStats";
+      docs: [
+        "Lifetime statistics of a player across all tables of the same denomination.",
+        "",
+        "Amounts of different mints can't be added up, so each mint has its own stats."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "player";
+            docs: ["Player the statistics belong to."];
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            docs: [
+              "Mint the amounts are denominated in, the default pubkey for lamports."
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "totalWagered";
+            docs: ["Total amount wagered across all positions."];
+            type: "u64";
+          },
+          {
+            name: "totalWon";
+            docs: ["Total winnings paid out, including returned stakes."];
+            type: "u64";
+          },
+          {
+            name: "betsPlaced";
+            docs: ["Amount of positions placed."];
+            type: "u64";
+          },
+          {
+            name: "wins";
+            docs: ["Amount of positions that won."];
+            type: "u64";
+          },
+          {
+            name: "biggestWin";
+            docs: ["Largest winnings paid out for a single bet."];
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6013;
-      name: "roundOver";
-      msg: "Round is no longer accepting bets";
+      name: "position";
+      docs: ["A single wager placed on the layout."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "betType";
+            type: {
+              defined: {
+                name: "betType";
+              };
+            };
+          },
+          {
+            name: "amount";
+            docs: ["Amount of lamports wagered on this position."];
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6014;
-      name: "roundAwaitingOutcome";
-      msg: "Round outcome is not yet available";
+      name: "referral";
+      docs: [
+        "Rewards earned by a referrer on the wagers of the players they referred to a table."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "referrer";
+            docs: ["Account that referred the players and claims the rewards."];
+            type: "pubkey";
+          },
+          {
+            name: "accruedRewards";
+            docs: ["Rewards credited and not yet claimed."];
+            type: "u64";
+          },
+          {
+            name: "totalRewards";
+            docs: ["Rewards credited since the referral was registered."];
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6015;
-      name: "insufficientVaultFunds";
-      msg: "Vault has insufficient funds to pay out winnings";
+      name: "referralRewardsClaimed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "referrer";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6016;
-      name: "invalidBetType";
-      msg: "Bet type is illegal";
+      name: "rouletteSpun";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "roundNumber";
+            type: "u64";
+          },
+          {
+            name: "maxExposure";
+            docs: [
+              "Worst-case lamports owed to players, across all outcomes of the round."
+            ];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6017;
-      name: "winningsAlreadyClaimed";
-      msg: "Winnings have already been claimed for this bet";
+      name: "round";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "roundNumber";
+            docs: [
+              "Number of the round.",
+              "",
+              "Starts at 1 and increments by 1 for each new round."
+            ];
+            type: "u64";
+          },
+          {
+            name: "payer";
+            docs: [
+              "Account that paid the rent of the round, refunded when the round is closed."
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "poolAmount";
+            docs: ["Lamports pooled from all bets in this round."];
+            type: "u64";
+          },
+          {
+            name: "isSpun";
+            docs: [
+              "Boolean indicating if the round has been spun and is awaiting VRF callback."
+            ];
+            type: "bool";
+          },
+          {
+            name: "spunTs";
+            docs: [
+              "Timestamp of the latest randomness request for this round."
+            ];
+            type: "i64";
+          },
+          {
+            name: "settledTs";
+            docs: ["Timestamp when the outcome of the round was set."];
+            type: "i64";
+          },
+          {
+            name: "betCount";
+            docs: [
+              "Amount of bets of this round that have not been closed yet."
+            ];
+            type: "u32";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "outcome";
+            docs: ["The number that won (0-36, with 37 representing 00)"];
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "payoutMultipliers";
+            docs: [
+              "Payout multipliers of the table when the round was opened, every bet of the round is paid with them."
+            ];
+            type: {
+              array: ["u8", 15];
+            };
+          },
+          {
+            name: "zeroRule";
+            docs: [
+              "Zero rule of the table when the round was opened, every bet of the round is settled with it."
+            ];
+            type: {
+              defined: {
+                name: "zeroRule";
+              };
+            };
+          },
+          {
+            name: "exposure";
+            docs: [
+              "Lamports owed to players for each possible outcome, indexed by outcome."
+            ];
+            type: {
+              array: ["u64", 38];
+            };
+          },
+          {
+            name: "winningStakes";
+            docs: [
+              "Lamports staked on positions that win for each possible outcome, indexed by outcome."
+            ];
+            type: {
+              array: ["u64", 38];
+            };
+          },
+          {
+            name: "totalWinningStake";
+            docs: [
+              "Lamports staked on positions that won the outcome, set when the round is settled."
+            ];
+            type: "u64";
+          },
+          {
+            name: "totalPayout";
+            docs: [
+              "Lamports owed to players for the outcome, set when the round is settled.",
+              "",
+              "Stakes held en prison are counted as returned."
+            ];
+            type: "u64";
+          },
+          {
+            name: "callerSeed";
+            docs: ["Seed of the latest randomness request for this round."];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "randomness";
+            docs: [
+              "Randomness the outcome was derived from, set when the round is settled."
+            ];
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6018;
-      name: "invalidAddress";
-      msg: "Address cannot be default pubkey";
+      name: "roundAdvanced";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "roundNumber";
+            type: "u64";
+          },
+          {
+            name: "outcome";
+            type: "u8";
+          },
+          {
+            name: "totalWinningStake";
+            docs: ["Lamports staked on positions that won."];
+            type: "u64";
+          },
+          {
+            name: "totalPayout";
+            docs: ["Lamports owed to players for the outcome."];
+            type: "u64";
+          },
+          {
+            name: "housePnl";
+            docs: [
+              "Pool of the round minus the total payout, negative when the house lost."
+            ];
+            type: "i64";
+          },
+          {
+            name: "callerSeed";
+            docs: ["Seed of the randomness request that was fulfilled."];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "randomness";
+            docs: ["Randomness the outcome was derived from."];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6019;
-      name: "vaultNotWithdrawable";
-      msg: "Vault does not have enough funds for withdrawal";
-    }
-  ];
-  types: [
-    {
-      name: "bet";
+      name: "roundClosed";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "player";
-            docs: ["Player who placed the bet."];
+            name: "table";
             type: "pubkey";
           },
           {
             name: "round";
-            docs: ["Round in which the bet was placed."];
             type: "pubkey";
           },
           {
-            name: "amount";
-            docs: ["Amount of lamports bet."];
+            name: "roundNumber";
             type: "u64";
           },
           {
-            name: "bump";
-            type: "u8";
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "spinRetried";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "table";
+            type: "pubkey";
           },
           {
-            name: "isClaimed";
-            docs: [
-              "Boolean that indicates if the prize for a winning bet has been claimed."
-            ];
-            type: "bool";
+            name: "round";
+            type: "pubkey";
           },
           {
-            name: "betType";
-            type: {
-              defined: {
-                name: "betType";
-              };
-            };
+            name: "roundNumber";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
           }
         ];
       };
     },
     {
-      name: "betPlaced";
+      name: "table";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "player";
+            name: "tableId";
+            docs: [
+              "Identifier chosen at initialization, mixed into every PDA seed of this table."
+            ];
+            type: "u64";
+          },
+          {
+            name: "admin";
+            docs: ["Authority of the table."];
             type: "pubkey";
           },
           {
-            name: "round";
+            name: "minimumBetAmount";
+            docs: ["Minimum bet amount in lamports."];
+            type: "u64";
+          },
+          {
+            name: "maximumBetAmounts";
+            docs: [
+              "Maximum bet amount in lamports for each bet kind, indexed by `BetKind`."
+            ];
+            type: {
+              array: ["u64", 15];
+            };
+          },
+          {
+            name: "maximumRoundLiability";
+            docs: [
+              "Maximum lamports a single round may owe to players on any outcome."
+            ];
+            type: "u64";
+          },
+          {
+            name: "currentRoundNumber";
+            docs: ["Number of the current round."];
+            type: "u64";
+          },
+          {
+            name: "nextRoundTs";
+            docs: ["Timestamp when round can be advanced."];
+            type: "i64";
+          },
+          {
+            name: "roundPeriodTs";
+            docs: ["Timestamp for how long each round lasts."];
+            type: "u64";
+          },
+          {
+            name: "spinTimeoutTs";
+            docs: [
+              "Seconds to wait for the VRF callback before the spin can be retried."
+            ];
+            type: "u64";
+          },
+          {
+            name: "claimWindowSecs";
+            docs: [
+              "Seconds after a round is settled during which its bets can be claimed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "crankFee";
+            docs: [
+              "Lamports paid from the vault to the cranker for each winning bet paid out by `settle_bets`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "forfeitedWinnings";
+            docs: [
+              "Lamports of winnings and imprisoned stakes forfeited by bets left unclaimed past the claim window."
+            ];
+            type: "u64";
+          },
+          {
+            name: "unclaimedLiability";
+            docs: [
+              "Lamports owed to players by settled rounds that have not been claimed yet."
+            ];
+            type: "u64";
+          },
+          {
+            name: "totalShares";
+            docs: [
+              "Shares of the bankroll, held by liquidity providers and the house."
+            ];
+            type: "u64";
+          },
+          {
+            name: "lpShares";
+            docs: [
+              "Shares of the bankroll held by liquidity providers, the rest belong to the house."
+            ];
+            type: "u64";
+          },
+          {
+            name: "lpCooldownSecs";
+            docs: [
+              "Seconds after a deposit before a liquidity provider can withdraw."
+            ];
+            type: "u64";
+          },
+          {
+            name: "rakeBps";
+            docs: [
+              "Rake in basis points charged on top of every bet, collected in the fee vault."
+            ];
+            type: "u16";
+          },
+          {
+            name: "referralBps";
+            docs: [
+              "Share of referred wagers in basis points credited to the referrer, paid out of the rake."
+            ];
+            type: "u16";
+          },
+          {
+            name: "unclaimedReferralRewards";
+            docs: [
+              "Referral rewards credited but not yet claimed, reserved in the fee vault."
+            ];
+            type: "u64";
+          },
+          {
+            name: "oracleQueue";
+            docs: [
+              "VRF oracle queue that spins of this table must request randomness from."
+            ];
             type: "pubkey";
           },
           {
-            name: "betType";
+            name: "mint";
+            docs: [
+              "Mint that bets and payouts are denominated in, lamports are used when `None`."
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "payoutMultipliers";
+            docs: [
+              "Winnings multiplier paid out for each bet kind, indexed by `BetKind`."
+            ];
+            type: {
+              array: ["u8", 15];
+            };
+          },
+          {
+            name: "wheel";
+            docs: [
+              "Layout of the wheel, which decides the possible outcomes and bets."
+            ];
             type: {
               defined: {
-                name: "betType";
+                name: "wheel";
               };
             };
           },
           {
-            name: "betAmount";
-            type: "u64";
+            name: "zeroRule";
+            docs: [
+              "What happens to even-money bets when the ball lands on zero."
+            ];
+            type: {
+              defined: {
+                name: "zeroRule";
+              };
+            };
           },
           {
-            name: "timestamp";
-            type: "i64";
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "vaultBump";
+            type: "u8";
+          },
+          {
+            name: "feeVaultBump";
+            type: "u8";
           }
         ];
       };
     },
     {
-      name: "betType";
+      name: "tableUpdated";
       type: {
-        kind: "enum";
-        variants: [
+        kind: "struct";
+        fields: [
           {
-            name: "straightUp";
-            fields: [
-              {
-                name: "number";
-                type: "u8";
-              }
-            ];
+            name: "table";
+            type: "pubkey";
           },
           {
-            name: "split";
-            fields: [
-              {
-                name: "numbers";
-                type: {
-                  array: ["u8", 2];
-                };
-              }
-            ];
+            name: "minimumBetAmount";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "street";
-            fields: [
-              {
-                name: "numbers";
-                type: {
-                  array: ["u8", 3];
-                };
-              }
-            ];
+            name: "maximumBetAmounts";
+            type: {
+              option: {
+                array: ["u64", 15];
+              };
+            };
           },
           {
-            name: "corner";
-            fields: [
-              {
-                name: "numbers";
-                type: {
-                  array: ["u8", 4];
-                };
-              }
-            ];
+            name: "maximumRoundLiability";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "fiveNumber";
+            name: "roundPeriodTs";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "line";
-            fields: [
-              {
-                name: "numbers";
-                type: {
-                  array: ["u8", 6];
-                };
-              }
-            ];
+            name: "spinTimeoutTs";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "column";
-            fields: [
-              {
-                name: "column";
-                type: "u8";
-              }
-            ];
+            name: "claimWindowSecs";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "dozen";
-            fields: [
-              {
-                name: "dozen";
-                type: "u8";
-              }
-            ];
+            name: "crankFee";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "red";
+            name: "lpCooldownSecs";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "black";
+            name: "rakeBps";
+            type: {
+              option: "u16";
+            };
           },
           {
-            name: "even";
+            name: "referralBps";
+            type: {
+              option: "u16";
+            };
           },
           {
-            name: "odd";
+            name: "oracleQueue";
+            type: {
+              option: "pubkey";
+            };
           },
           {
-            name: "high";
+            name: "payoutMultipliers";
+            type: {
+              option: {
+                array: ["u8", 15];
+              };
+            };
           },
           {
-            name: "low";
-          }
-        ];
-      };
-    },
-    {
-      name: "rouletteSpun";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "round";
-            type: "pubkey";
+            name: "zeroRule";
+            type: {
+              option: {
+                defined: {
+                  name: "zeroRule";
+                };
+              };
+            };
           },
           {
-            name: "roundNumber";
-            type: "u64";
+            name: "newAdmin";
+            type: {
+              option: "pubkey";
+            };
           },
           {
             name: "timestamp";
@@ -790,132 +4220,156 @@ export type MagicRoulette = {
       };
     },
     {
-      name: "round";
+      name: "updateTableArgs";
+      docs: ["Table settings to change, left untouched when `None`."];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "roundNumber";
-            docs: [
-              "Number of the round.",
-              "",
-              "Starts at 1 and increments by 1 for each new round."
-            ];
-            type: "u64";
+            name: "minimumBetAmount";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "poolAmount";
-            docs: ["Lamports pooled from all bets in this round."];
-            type: "u64";
+            name: "maximumBetAmounts";
+            type: {
+              option: {
+                array: ["u64", 15];
+              };
+            };
           },
           {
-            name: "isSpun";
-            docs: [
-              "Boolean indicating if the round has been spun and is awaiting VRF callback."
-            ];
-            type: "bool";
+            name: "maximumRoundLiability";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "bump";
-            type: "u8";
+            name: "roundPeriodTs";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "outcome";
-            docs: ["The number that won (0-36, with 37 representing 00)"];
+            name: "spinTimeoutTs";
             type: {
-              option: "u8";
+              option: "u64";
             };
-          }
-        ];
-      };
-    },
-    {
-      name: "roundAdvanced";
-      type: {
-        kind: "struct";
-        fields: [
+          },
           {
-            name: "round";
-            type: "pubkey";
+            name: "claimWindowSecs";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "roundNumber";
-            type: "u64";
+            name: "crankFee";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "outcome";
-            type: "u8";
+            name: "lpCooldownSecs";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "timestamp";
-            type: "i64";
+            name: "rakeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "referralBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "oracleQueue";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "payoutMultipliers";
+            type: {
+              option: {
+                array: ["u8", 15];
+              };
+            };
+          },
+          {
+            name: "zeroRule";
+            type: {
+              option: {
+                defined: {
+                  name: "zeroRule";
+                };
+              };
+            };
+          },
+          {
+            name: "newAdmin";
+            type: {
+              option: "pubkey";
+            };
           }
         ];
       };
     },
     {
-      name: "table";
+      name: "vaultWithdrawn";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "admin";
-            docs: ["Authority of the table."];
+            name: "table";
             type: "pubkey";
           },
           {
-            name: "minimumBetAmount";
-            docs: ["Minimum bet amount in lamports."];
-            type: "u64";
-          },
-          {
-            name: "currentRoundNumber";
-            docs: ["Number of the current round."];
+            name: "amount";
             type: "u64";
           },
           {
-            name: "nextRoundTs";
-            docs: ["Timestamp when round can be advanced."];
+            name: "timestamp";
             type: "i64";
-          },
-          {
-            name: "roundPeriodTs";
-            docs: ["Timestamp for how long each round lasts."];
-            type: "u64";
-          },
+          }
+        ];
+      };
+    },
+    {
+      name: "wheel";
+      type: {
+        kind: "enum";
+        variants: [
           {
-            name: "bump";
-            type: "u8";
+            name: "american";
           },
           {
-            name: "vaultBump";
-            type: "u8";
+            name: "european";
           }
         ];
       };
     },
     {
-      name: "tableUpdated";
+      name: "winningsClaimed";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "minimumBetAmount";
-            type: {
-              option: "u64";
-            };
+            name: "table";
+            type: "pubkey";
           },
           {
-            name: "roundPeriodTs";
-            type: {
-              option: "u64";
-            };
+            name: "player";
+            type: "pubkey";
           },
           {
-            name: "newAdmin";
-            type: {
-              option: "pubkey";
-            };
+            name: "winnings";
+            type: "u64";
           },
           {
             name: "timestamp";
@@ -925,12 +4379,25 @@ export type MagicRoulette = {
       };
     },
     {
-      name: "vaultWithdrawn";
+      name: "winningsSwept";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "amount";
+            name: "table";
+            type: "pubkey";
+          },
+          {
+            name: "player";
+            type: "pubkey";
+          },
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "winnings";
+            docs: ["Winnings and imprisoned stakes forfeited by the player."];
             type: "u64";
           },
           {
@@ -941,21 +4408,18 @@ export type MagicRoulette = {
       };
     },
     {
-      name: "winningsClaimed";
+      name: "zeroRule";
       type: {
-        kind: "struct";
-        fields: [
+        kind: "enum";
+        variants: [
           {
-            name: "player";
-            type: "pubkey";
+            name: "standard";
           },
           {
-            name: "winnings";
-            type: "u64";
+            name: "laPartage";
           },
           {
-            name: "timestamp";
-            type: "i64";
+            name: "enPrison";
           }
         ];
       };
@@ -967,6 +4431,61 @@ export type MagicRoulette = {
       type: "bytes";
       value: "[98, 101, 116]";
     },
+    {
+      name: "feeVaultSeed";
+      type: "bytes";
+      value: "[102, 101, 101, 95, 118, 97, 117, 108, 116]";
+    },
+    {
+      name: "historySeed";
+      type: "bytes";
+      value: "[104, 105, 115, 116, 111, 114, 121]";
+    },
+    {
+      name: "lpPositionSeed";
+      type: "bytes";
+      value: "[108, 112, 95, 112, 111, 115, 105, 116, 105, 111, 110]";
+    },
+    {
+      name: "maxBasisPoints";
+      type: "u16";
+      value: "10000";
+    },
+    {
+      name: "maxCrankFeeBps";
+      docs: [
+        "Highest crank fee a table can pay for each bet, 1% of its minimum bet."
+      ];
+      type: "u16";
+      value: "100";
+    },
+    {
+      name: "maxRakeBps";
+      docs: ["Highest rake a table can take from bets, 10%."];
+      type: "u16";
+      value: "1000";
+    },
+    {
+      name: "minHouseShares";
+      docs: [
+        "Least shares the house holds while liquidity providers are in the pool, 0.1 SOL at the initial share price.",
+        "",
+        "Donations to the vault are shared with the house, so inflating the share price to round down",
+        "the shares of later deposits costs far more than it gains."
+      ];
+      type: "u64";
+      value: "100000000";
+    },
+    {
+      name: "playerStatsSeed";
+      type: "bytes";
+      value: "[112, 108, 97, 121, 101, 114, 95, 115, 116, 97, 116, 115]";
+    },
+    {
+      name: "referralSeed";
+      type: "bytes";
+      value: "[114, 101, 102, 101, 114, 114, 97, 108]";
+    },
     {
       name: "roundSeed";
       type: "bytes";
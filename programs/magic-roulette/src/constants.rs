@@ -8,3 +8,27 @@ pub const ROUND_SEED: &[u8] = b"round";
 pub const BET_SEED: &[u8] = b"bet";
 #[constant]
 pub const VAULT_SEED: &[u8] = b"vault";
+#[constant]
+pub const LP_POSITION_SEED: &[u8] = b"lp_position";
+#[constant]
+pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+#[constant]
+pub const REFERRAL_SEED: &[u8] = b"referral";
+#[constant]
+pub const PLAYER_STATS_SEED: &[u8] = b"player_stats";
+#[constant]
+pub const HISTORY_SEED: &[u8] = b"history";
+#[constant]
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+/// Highest rake a table can take from bets, 10%.
+#[constant]
+pub const MAX_RAKE_BPS: u16 = 1_000;
+/// Highest crank fee a table can pay for each bet, 1% of its minimum bet.
+#[constant]
+pub const MAX_CRANK_FEE_BPS: u16 = 100;
+/// Least shares the house holds while liquidity providers are in the pool, 0.1 SOL at the initial share price.
+///
+/// Donations to the vault are shared with the house, so inflating the share price to round down
+/// the shares of later deposits costs far more than it gains.
+#[constant]
+pub const MIN_HOUSE_SHARES: u64 = 100_000_000;
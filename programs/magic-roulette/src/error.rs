@@ -42,4 +42,80 @@ pub enum MagicRouletteError {
     InvalidAddress,
     #[msg("Vault does not have enough funds for withdrawal")]
     VaultNotWithdrawable,
+    #[msg("Bet has reached the maximum number of positions")]
+    TooManyPositions,
+    #[msg("At least one position must be placed")]
+    NoPositions,
+    #[msg("Spin timeout must be greater than zero")]
+    InvalidSpinTimeout,
+    #[msg("Round has not been spun yet")]
+    RoundNotSpun,
+    #[msg("Randomness request has not timed out yet")]
+    SpinNotTimedOut,
+    #[msg("Payout multipliers must be greater than zero and not exceed true odds")]
+    InvalidPayoutMultipliers,
+    #[msg("Round does not hold even-money bets en prison")]
+    EnPrisonDisabled,
+    #[msg("Bet has no stake held en prison")]
+    NoImprisonedStake,
+    #[msg("Imprisoned stake has already been carried into a later round")]
+    StakeAlreadyCarried,
+    #[msg("Maximum bet amount must not be below the minimum bet amount")]
+    InvalidMaximumBetAmount,
+    #[msg("Maximum round liability must be greater than zero")]
+    InvalidMaximumRoundLiability,
+    #[msg("Bet above maximum bet amount")]
+    BetAboveMaximum,
+    #[msg("Bet would exceed the maximum round liability")]
+    RoundLiabilityExceeded,
+    #[msg("Vault cannot cover the worst-case payout of the round")]
+    RoundInsolvent,
+    #[msg("Bet still has winnings or refunds to claim")]
+    BetNotLosing,
+    #[msg("Claim window must be greater than zero")]
+    InvalidClaimWindow,
+    #[msg("Round still has bets to settle")]
+    RoundNotSettled,
+    #[msg("Claim window of the round has expired")]
+    ClaimWindowExpired,
+    #[msg("Claim window of the round has not expired yet")]
+    ClaimWindowOpen,
+    #[msg("Amount released exceeds the unclaimed liability of the table")]
+    LiabilityUnderflow,
+    #[msg("Crank fee must not exceed the maximum crank fee")]
+    InvalidCrankFee,
+    #[msg("Mint does not match the mint of the table")]
+    InvalidMint,
+    #[msg("Token accounts are required for token-denominated tables")]
+    MissingTokenAccount,
+    #[msg("Mint has unsupported Token-2022 extensions")]
+    UnsupportedMint,
+    #[msg("Liquidity pool only supports lamport-denominated tables")]
+    LpTokenTableUnsupported,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Bankroll is depleted, shares can't be priced")]
+    BankrollDepleted,
+    #[msg("Not enough shares for withdrawal")]
+    InsufficientShares,
+    #[msg("Deposit is still in cooldown")]
+    LpCooldownActive,
+    #[msg("House bankroll is too low for liquidity providers to join")]
+    HouseBankrollTooLow,
+    #[msg("Rake must not exceed the maximum rake")]
+    InvalidRakeBps,
+    #[msg("Referral share must not exceed the rake")]
+    InvalidReferralBps,
+    #[msg("Players cannot refer themselves")]
+    SelfReferral,
+    #[msg("No referral rewards to claim")]
+    NoReferralRewards,
+    #[msg("Round has already been spun")]
+    RoundAlreadySpun,
+    #[msg("Round has already been settled")]
+    RoundAlreadySettled,
+    #[msg("Round is not the current round of the table")]
+    RoundNotCurrent,
+    #[msg("Randomness does not answer the latest request of the round")]
+    StaleRandomnessRequest,
 }
@@ -1,33 +1,113 @@
 use anchor_lang::prelude::*;
 
-use crate::BetType;
+use crate::{BetKind, BetType, ZeroRule};
 
 #[event]
 pub struct RoundAdvanced {
+    pub table: Pubkey,
     pub round: Pubkey,
     pub round_number: u64,
     pub outcome: u8,
+    /// Lamports staked on positions that won.
+    pub total_winning_stake: u64,
+    /// Lamports owed to players for the outcome.
+    pub total_payout: u64,
+    /// Pool of the round minus the total payout, negative when the house lost.
+    pub house_pnl: i64,
+    /// Seed of the randomness request that was fulfilled.
+    pub caller_seed: [u8; 32],
+    /// Randomness the outcome was derived from.
+    pub randomness: [u8; 32],
     pub timestamp: i64,
 }
 
 #[event]
 pub struct WinningsClaimed {
+    pub table: Pubkey,
     pub player: Pubkey,
     pub winnings: u64,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BetsSettled {
+    pub table: Pubkey,
+    pub round: Pubkey,
+    pub cranker: Pubkey,
+    /// Amount of bets settled in the batch.
+    pub settled_count: u64,
+    /// Winnings paid out to players.
+    pub winnings: u64,
+    /// Lamports paid to the cranker.
+    pub crank_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ImprisonedStakeCarried {
+    pub table: Pubkey,
+    pub player: Pubkey,
+    /// Round in which the stake went en prison.
+    pub round: Pubkey,
+    /// Round the stake was carried into.
+    pub next_round: Pubkey,
+    pub stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BetClosed {
+    pub table: Pubkey,
+    pub player: Pubkey,
+    pub round: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundClosed {
+    pub table: Pubkey,
+    pub round: Pubkey,
+    pub round_number: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WinningsSwept {
+    pub table: Pubkey,
+    pub player: Pubkey,
+    pub round: Pubkey,
+    /// Winnings and imprisoned stakes forfeited by the player.
+    pub winnings: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct BetPlaced {
+    pub table: Pubkey,
     pub player: Pubkey,
     pub round: Pubkey,
     pub bet_type: BetType,
     pub bet_amount: u64,
+    /// Rake charged on the bet, paid into the fee vault.
+    pub rake: u64,
+    /// Referrer credited with a share of the bet, if any.
+    pub referrer: Option<Pubkey>,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct RouletteSpun {
+    pub table: Pubkey,
+    pub round: Pubkey,
+    pub round_number: u64,
+    /// Worst-case lamports owed to players, across all outcomes of the round.
+    pub max_exposure: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SpinRetried {
+    pub table: Pubkey,
     pub round: Pubkey,
     pub round_number: u64,
     pub timestamp: i64,
@@ -35,14 +115,62 @@ pub struct RouletteSpun {
 
 #[event]
 pub struct TableUpdated {
+    pub table: Pubkey,
     pub minimum_bet_amount: Option<u64>,
+    pub maximum_bet_amounts: Option<[u64; BetKind::COUNT]>,
+    pub maximum_round_liability: Option<u64>,
     pub round_period_ts: Option<u64>,
+    pub spin_timeout_ts: Option<u64>,
+    pub claim_window_secs: Option<u64>,
+    pub crank_fee: Option<u64>,
+    pub lp_cooldown_secs: Option<u64>,
+    pub rake_bps: Option<u16>,
+    pub referral_bps: Option<u16>,
+    pub oracle_queue: Option<Pubkey>,
+    pub payout_multipliers: Option<[u8; BetKind::COUNT]>,
+    pub zero_rule: Option<ZeroRule>,
     pub new_admin: Option<Pubkey>,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct VaultWithdrawn {
+    pub table: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub table: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub table: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LpDeposited {
+    pub table: Pubkey,
+    pub provider: Pubkey,
+    pub amount: u64,
+    /// Shares minted for the deposit.
+    pub shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LpWithdrawn {
+    pub table: Pubkey,
+    pub provider: Pubkey,
     pub amount: u64,
+    /// Shares burned for the withdrawal.
+    pub shares: u64,
     pub timestamp: i64,
 }
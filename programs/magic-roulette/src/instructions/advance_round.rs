@@ -1,10 +1,11 @@
 use std::ops::Add;
 
 use anchor_lang::prelude::*;
-use ephemeral_vrf_sdk::{consts::VRF_PROGRAM_IDENTITY, rnd::random_u8_with_range};
+use ephemeral_vrf_sdk::consts::VRF_PROGRAM_IDENTITY;
 
 use crate::{
-    error::MagicRouletteError, events::RoundAdvanced, BetType, Round, Table, ROUND_SEED, TABLE_SEED,
+    error::MagicRouletteError, events::RoundAdvanced, utils::spin_outcome, History, Round, Table,
+    HISTORY_SEED, ROUND_SEED, TABLE_SEED,
 };
 
 #[derive(Accounts)]
@@ -13,45 +14,91 @@ pub struct AdvanceRound<'info> {
     pub vrf_program_identity: Signer<'info>,
     #[account(
         mut,
-        seeds = [TABLE_SEED],
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
         bump = table.bump,
     )]
     pub table: Account<'info, Table>,
+    // seeded by its own number, so a late callback fails on the round check rather than the seeds
     #[account(
         mut,
-        seeds = [ROUND_SEED, table.current_round_number.to_le_bytes().as_ref()],
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            current_round.round_number.to_le_bytes().as_ref()
+        ],
         bump = current_round.bump,
+        constraint = current_round.round_number == table.current_round_number @ MagicRouletteError::RoundNotCurrent,
     )]
-    pub current_round: Account<'info, Round>,
+    pub current_round: Box<Account<'info, Round>>,
     #[account(
         mut,
-        seeds = [ROUND_SEED, table.current_round_number.add(1).to_le_bytes().as_ref()],
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            current_round.round_number.add(1).to_le_bytes().as_ref()
+        ],
         bump = new_round.bump,
     )]
-    pub new_round: Account<'info, Round>,
+    pub new_round: Box<Account<'info, Round>>,
+    #[account(
+        mut,
+        seeds = [HISTORY_SEED, table.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: AccountLoader<'info, History>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> AdvanceRound<'info> {
-    pub fn handler(&mut self, randomness: [u8; 32]) -> Result<()> {
-        let outcome = random_u8_with_range(&randomness, 0, BetType::MAX_OUTCOME);
+    pub fn handler(&mut self, randomness: [u8; 32], caller_seed: [u8; 32]) -> Result<()> {
+        require!(self.current_round.is_spun, MagicRouletteError::RoundNotSpun);
+        require!(
+            self.current_round.outcome.is_none(),
+            MagicRouletteError::RoundAlreadySettled
+        );
+        // a retry replaces the seed of the round, so randomness of an earlier request is rejected
+        require!(
+            caller_seed == self.current_round.caller_seed,
+            MagicRouletteError::StaleRandomnessRequest
+        );
 
-        self.current_round.outcome = Some(outcome);
-        self.table.current_round_number += 1;
+        let outcome = spin_outcome(&randomness, self.table.wheel);
 
         let now = Clock::get()?.unix_timestamp;
 
+        self.current_round.settle(outcome, randomness, now);
+        self.history
+            .load_mut()?
+            .push(self.current_round.round_number, outcome, now);
+        self.table.current_round_number += 1;
+
+        // everything the outcome owes to players stays reserved in the vault until claimed
+        self.table.unclaimed_liability = self
+            .table
+            .unclaimed_liability
+            .checked_add(self.current_round.total_payout)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
         // round_period_ts is only set on successful callback
         self.table.next_round_ts = now
             .checked_add(self.table.round_period_ts as i64)
             .ok_or(MagicRouletteError::MathOverflow)?;
 
-        let now = Clock::get()?.unix_timestamp;
+        let house_pnl = self
+            .current_round
+            .house_pnl()
+            .ok_or(MagicRouletteError::MathOverflow)?;
 
         emit!(RoundAdvanced {
+            table: self.table.key(),
             round: self.current_round.key(),
             round_number: self.current_round.round_number,
             outcome,
+            total_winning_stake: self.current_round.total_winning_stake,
+            total_payout: self.current_round.total_payout,
+            house_pnl,
+            caller_seed: self.current_round.caller_seed,
+            randomness,
             timestamp: now,
         });
 
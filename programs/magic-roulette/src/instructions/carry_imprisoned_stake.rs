@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MagicRouletteError, events::ImprisonedStakeCarried, utils::close, Bet, Round, Table,
+    ZeroRule, BET_SEED, ROUND_SEED, TABLE_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CarryImprisonedStake<'info> {
+    /// Anyone can carry imprisoned stakes, pays the rent of the next bet if it has to be created
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Player who placed the bet, receives the rent back
+    #[account(mut)]
+    pub player: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    /// Round in which the stake went en prison
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            round.round_number.to_le_bytes().as_ref()
+        ],
+        bump = round.bump
+    )]
+    pub round: Box<Account<'info, Round>>,
+    /// Open round of the table, whose spin decides whether the imprisoned stake is returned
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
+        bump = next_round.bump
+    )]
+    pub next_round: Box<Account<'info, Round>>,
+    #[account(
+        mut,
+        seeds = [
+            BET_SEED,
+            table.key().as_ref(),
+            round.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = bet.bump,
+    )]
+    pub bet: Account<'info, Bet>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Bet::DISCRIMINATOR.len() + Bet::INIT_SPACE,
+        seeds = [
+            BET_SEED,
+            table.key().as_ref(),
+            next_round.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump,
+    )]
+    pub next_bet: Account<'info, Bet>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CarryImprisonedStake<'info> {
+    pub fn handler(&mut self, bumps: &CarryImprisonedStakeBumps) -> Result<()> {
+        require!(
+            self.round.zero_rule == ZeroRule::EnPrison,
+            MagicRouletteError::EnPrisonDisabled
+        );
+        require!(
+            !self.bet.is_carried,
+            MagicRouletteError::StakeAlreadyCarried
+        );
+
+        let outcome = self
+            .round
+            .outcome
+            .ok_or(MagicRouletteError::RoundAwaitingOutcome)?;
+
+        require!(
+            self.bet.is_awaiting_carry(outcome, self.round.zero_rule),
+            MagicRouletteError::NoImprisonedStake
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // stakes left en prison past the claim window are swept by the admin
+        require!(
+            !self
+                .round
+                .is_claim_expired(now, self.table.claim_window_secs),
+            MagicRouletteError::ClaimWindowExpired
+        );
+        // stake rides on the next spin, so it can't join a round that was already spun
+        require!(
+            !self.next_round.is_spun,
+            MagicRouletteError::RoundAlreadySpun
+        );
+
+        // next bet is shared with the positions the player places in that round, so only set it up once
+        if self.next_bet.player == Pubkey::default() {
+            self.next_bet.set_inner(Bet {
+                player: self.player.key(),
+                round: self.next_round.key(),
+                amount: 0,
+                bump: bumps.next_bet,
+                is_claimed: false,
+                is_carried: false,
+                positions: Vec::new(),
+                imprisoned_positions: Vec::with_capacity(Bet::MAX_IMPRISONED_POSITIONS),
+            });
+
+            self.next_round.bet_count = self
+                .next_round
+                .bet_count
+                .checked_add(1)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+        }
+
+        for position in self
+            .bet
+            .positions
+            .iter()
+            .filter(|position| position.bet_type.is_even_money())
+        {
+            self.next_bet
+                .carry(position)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+            self.next_round
+                .add_imprisoned_exposure(position, self.table.wheel)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+        }
+
+        let stake = self
+            .bet
+            .imprisoned_stake()
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        // reserve of the stake moves from the settled round into the pool of the next one
+        self.table.unclaimed_liability = self
+            .table
+            .unclaimed_liability
+            .checked_sub(stake)
+            .ok_or(MagicRouletteError::LiabilityUnderflow)?;
+        self.next_round.pool_amount = self
+            .next_round
+            .pool_amount
+            .checked_add(stake)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        self.bet.is_carried = true;
+
+        let winnings = self
+            .bet
+            .winnings(
+                outcome,
+                &self.round.payout_multipliers,
+                self.round.zero_rule,
+            )
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        // return the rent once there is nothing left to claim on the bet
+        if self.bet.is_claimed || winnings == 0 {
+            close(self.bet.to_account_info(), self.player.to_account_info())?;
+
+            self.round.bet_count = self.round.bet_count.saturating_sub(1);
+        }
+
+        emit!(ImprisonedStakeCarried {
+            table: self.table.key(),
+            player: self.player.key(),
+            round: self.round.key(),
+            next_round: self.next_round.key(),
+            stake,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
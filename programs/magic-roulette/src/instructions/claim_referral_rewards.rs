@@ -0,0 +1,110 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::MagicRouletteError,
+    events::ReferralRewardsClaimed,
+    utils::{require_token_account, transfer_tokens},
+    Referral, Table, FEE_VAULT_SEED, REFERRAL_SEED, TABLE_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    /// CHECK: Fee vault for holding the rake, system account
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, table.key().as_ref()],
+        bump = table.fee_vault_bump
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, table.key().as_ref(), referrer.key().as_ref()],
+        bump = referral.bump,
+    )]
+    pub referral: Account<'info, Referral>,
+    #[account(constraint = table.mint == Some(mint.key()) @ MagicRouletteError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = referrer,
+        token::token_program = token_program,
+    )]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimReferralRewards<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        let amount = self.referral.accrued_rewards;
+
+        require!(amount > 0, MagicRouletteError::NoReferralRewards);
+
+        self.referral.accrued_rewards = 0;
+        self.table.unclaimed_referral_rewards =
+            self.table.unclaimed_referral_rewards.saturating_sub(amount);
+
+        let table_key = self.table.key();
+        let fee_vault_seeds: &[&[u8]] = &[
+            FEE_VAULT_SEED,
+            table_key.as_ref(),
+            &[self.table.fee_vault_bump],
+        ];
+
+        // rewards are reserved out of the rake, which is collected in the fee vault
+        if self.table.mint.is_none() {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.fee_vault.to_account_info(),
+                        to: self.referrer.to_account_info(),
+                    },
+                )
+                .with_signer(&[fee_vault_seeds]),
+                amount,
+            )?;
+        } else {
+            transfer_tokens(
+                require_token_account(&self.token_program)?,
+                require_token_account(&self.mint)?,
+                require_token_account(&self.fee_vault_token_account)?.to_account_info(),
+                require_token_account(&self.referrer_token_account)?.to_account_info(),
+                self.fee_vault.to_account_info(),
+                amount,
+                &[fee_vault_seeds],
+            )?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        emit!(ReferralRewardsClaimed {
+            table: table_key,
+            referrer: self.referrer.key(),
+            amount,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -1,11 +1,12 @@
-use anchor_lang::{
-    prelude::*,
-    system_program::{transfer, Transfer},
-};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    error::MagicRouletteError, events::WinningsClaimed, Bet, Round, Table, BET_SEED, ID,
-    ROUND_SEED, TABLE_SEED, VAULT_SEED,
+    error::MagicRouletteError,
+    events::WinningsClaimed,
+    utils::{close, require_token_account, Vault},
+    Bet, PlayerStats, Round, Table, BET_SEED, ID, PLAYER_STATS_SEED, ROUND_SEED, TABLE_SEED,
+    VAULT_SEED,
 };
 
 #[derive(Accounts)]
@@ -14,15 +15,43 @@ pub struct ClaimWinnings<'info> {
     pub player: Signer<'info>,
     #[account(
         mut,
-        seeds = [VAULT_SEED],
+        seeds = [VAULT_SEED, table.key().as_ref()],
         bump = table.vault_bump,
     )]
     pub vault: SystemAccount<'info>,
     #[account(
-        seeds = [TABLE_SEED],
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
         bump = table.bump
     )]
     pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED,
+            player.key().as_ref(),
+            table.mint.unwrap_or_default().as_ref()
+        ],
+        bump = player_stats.bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+    #[account(constraint = table.mint == Some(mint.key()) @ MagicRouletteError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = player,
+        token::token_program = token_program,
+    )]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -30,11 +59,15 @@ impl<'info> ClaimWinnings<'info> {
     pub fn handler(ctx: Context<'_, '_, '_, 'info, ClaimWinnings<'info>>) -> Result<()> {
         let ClaimWinnings {
             player,
-            system_program,
-            vault,
+            table,
+            player_stats,
             ..
         } = ctx.accounts;
 
+        let table_key = table.key();
+        let player_key = player.key();
+        let now = Clock::get()?.unix_timestamp;
+
         let remaining_accounts = &mut ctx.remaining_accounts.iter();
 
         let mut winnings: u64 = 0;
@@ -43,20 +76,29 @@ impl<'info> ClaimWinnings<'info> {
         while let (Some(round_account), Some(bet_account)) =
             (remaining_accounts.next(), remaining_accounts.next())
         {
-            let round = Round::try_deserialize(&mut &round_account.data.borrow_mut()[..])?;
-            let round_seeds = &[ROUND_SEED, &round.round_number.to_le_bytes(), &[round.bump]];
+            let mut round = Round::try_deserialize(&mut &round_account.data.borrow_mut()[..])?;
+            let round_seeds = &[
+                ROUND_SEED,
+                table_key.as_ref(),
+                &round.round_number.to_le_bytes(),
+                &[round.bump],
+            ];
             let round_pda = Pubkey::create_program_address(round_seeds, &ID).unwrap();
 
             require!(
                 round_pda == round_account.key(),
                 MagicRouletteError::InvalidRound
             );
+            require!(
+                !round.is_claim_expired(now, table.claim_window_secs),
+                MagicRouletteError::ClaimWindowExpired
+            );
 
             let mut bet = Bet::try_deserialize(&mut &bet_account.data.borrow_mut()[..])?;
             let round_key = round_account.key();
-            let player_key = player.key();
             let bet_seeds = &[
                 BET_SEED,
+                table_key.as_ref(),
                 round_key.as_ref(),
                 player_key.as_ref(),
                 &[bet.bump],
@@ -64,31 +106,40 @@ impl<'info> ClaimWinnings<'info> {
             let bet_pda = Pubkey::create_program_address(bet_seeds, &ID).unwrap();
 
             require!(bet_pda == bet_account.key(), MagicRouletteError::InvalidBet);
-            require!(
-                bet.bet_type.is_winner(
-                    round
-                        .outcome
-                        .ok_or(MagicRouletteError::RoundAwaitingOutcome)?
-                ),
-                MagicRouletteError::BetNotWinning
-            );
             require!(!bet.is_claimed, MagicRouletteError::WinningsAlreadyClaimed);
 
-            // payout = original bet amount * multiplier + original bet amount
-            let bet_payout = bet
-                .amount
-                .checked_mul(bet.bet_type.payout_multiplier() as u64)
-                .ok_or(MagicRouletteError::MathOverflow)?
-                .checked_add(bet.amount)
+            let outcome = round
+                .outcome
+                .ok_or(MagicRouletteError::RoundAwaitingOutcome)?;
+
+            // payout = original bet amount * multiplier + original bet amount, for each winning position
+            let bet_winnings = bet
+                .winnings(outcome, &round.payout_multipliers, round.zero_rule)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+
+            require!(bet_winnings > 0, MagicRouletteError::BetNotWinning);
+
+            player_stats
+                .record_win(bet_winnings, bet.winning_positions(outcome))
                 .ok_or(MagicRouletteError::MathOverflow)?;
 
             winnings = winnings
-                .checked_add(bet_payout)
+                .checked_add(bet_winnings)
                 .ok_or(MagicRouletteError::MathOverflow)?;
             bet.is_claimed = true;
 
-            let mut data = bet_account.try_borrow_mut_data()?;
-            bet.serialize(&mut &mut data[Bet::DISCRIMINATOR.len()..])?;
+            // bet is kept open only while a stake held en prison can still be carried
+            if bet.is_awaiting_carry(outcome, round.zero_rule) {
+                let mut data = bet_account.try_borrow_mut_data()?;
+                bet.serialize(&mut &mut data[Bet::DISCRIMINATOR.len()..])?;
+            } else {
+                close(bet_account.clone(), player.to_account_info())?;
+
+                round.bet_count = round.bet_count.saturating_sub(1);
+
+                let mut data = round_account.try_borrow_mut_data()?;
+                round.serialize(&mut &mut data[Round::DISCRIMINATOR.len()..])?;
+            }
         }
 
         // length of remaining accounts must be even
@@ -97,34 +148,40 @@ impl<'info> ClaimWinnings<'info> {
             MagicRouletteError::InsufficientRemainingAccounts
         );
 
-        // although chances are small, it is possible for vault to not have enough funds to pay out winnings
-        require!(
-            vault.lamports() >= winnings,
-            MagicRouletteError::InsufficientVaultFunds
-        );
+        // winnings are computed with the settings snapshotted on each round, so they match what was reserved
+        table.unclaimed_liability = table
+            .unclaimed_liability
+            .checked_sub(winnings)
+            .ok_or(MagicRouletteError::LiabilityUnderflow)?;
 
-        let vault_seeds: &[&[u8]] = &[VAULT_SEED, &[ctx.accounts.table.vault_bump]];
-
-        transfer(
-            CpiContext::new(
-                system_program.to_account_info(),
-                Transfer {
-                    from: vault.to_account_info(),
-                    to: player.to_account_info(),
-                },
-            )
-            .with_signer(&[vault_seeds]),
-            winnings,
-        )?;
+        let destination = match ctx.accounts.table.mint {
+            Some(_) => require_token_account(&ctx.accounts.player_token_account)?.to_account_info(),
+            None => ctx.accounts.player.to_account_info(),
+        };
 
-        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts
+            .vault_accounts()
+            .transfer_out(destination, winnings)?;
 
         emit!(WinningsClaimed {
-            player: player.key(),
+            table: table_key,
+            player: player_key,
             winnings,
             timestamp: now,
         });
 
         Ok(())
     }
+
+    /// Funds of the table, held in the vault token account for token-denominated tables.
+    fn vault_accounts(&mut self) -> Vault<'_, 'info> {
+        Vault {
+            table: &self.table,
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.as_mut(),
+            mint: self.mint.as_ref(),
+            token_program: self.token_program.as_ref(),
+            system_program: &self.system_program,
+        }
+    }
 }
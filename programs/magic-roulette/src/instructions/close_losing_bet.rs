@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MagicRouletteError, events::BetClosed, Bet, Round, Table, BET_SEED, ROUND_SEED,
+    TABLE_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CloseLosingBet<'info> {
+    /// Player who placed the bet, receives the rent back
+    #[account(mut)]
+    pub player: SystemAccount<'info>,
+    #[account(
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            round.round_number.to_le_bytes().as_ref()
+        ],
+        bump = round.bump
+    )]
+    pub round: Box<Account<'info, Round>>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            BET_SEED,
+            table.key().as_ref(),
+            round.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = bet.bump,
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+impl<'info> CloseLosingBet<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        let outcome = self
+            .round
+            .outcome
+            .ok_or(MagicRouletteError::RoundAwaitingOutcome)?;
+
+        let winnings = self
+            .bet
+            .winnings(
+                outcome,
+                &self.round.payout_multipliers,
+                self.round.zero_rule,
+            )
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        require!(
+            winnings == 0 && !self.bet.is_awaiting_carry(outcome, self.round.zero_rule),
+            MagicRouletteError::BetNotLosing
+        );
+
+        self.round.bet_count = self.round.bet_count.saturating_sub(1);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        emit!(BetClosed {
+            table: self.table.key(),
+            player: self.player.key(),
+            round: self.round.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::MagicRouletteError, events::RoundClosed, Round, Table, ROUND_SEED, TABLE_SEED};
+
+#[derive(Accounts)]
+pub struct CloseRound<'info> {
+    /// Account that paid for the round, receives the rent back
+    #[account(
+        mut,
+        address = round.payer @ MagicRouletteError::InvalidAddress
+    )]
+    pub payer: SystemAccount<'info>,
+    #[account(
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            round.round_number.to_le_bytes().as_ref()
+        ],
+        bump = round.bump
+    )]
+    pub round: Box<Account<'info, Round>>,
+}
+
+impl<'info> CloseRound<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        require!(
+            self.round.outcome.is_some(),
+            MagicRouletteError::RoundAwaitingOutcome
+        );
+
+        // every bet needs the round to be settled, closed or swept, so it can only go once none are left
+        require!(
+            self.round.bet_count == 0,
+            MagicRouletteError::RoundNotSettled
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        emit!(RoundClosed {
+            table: self.table.key(),
+            round: self.round.key(),
+            round_number: self.round.round_number,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
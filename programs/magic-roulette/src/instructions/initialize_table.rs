@@ -2,36 +2,104 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::MagicRouletteError,
+    utils::{has_supported_extensions, require_token_account, transfer_tokens},
+    BetKind, History, Round, Table, Wheel, ZeroRule, FEE_VAULT_SEED, HISTORY_SEED, ROUND_SEED,
+    TABLE_SEED, VAULT_SEED,
+};
 
-use crate::{error::MagicRouletteError, Round, Table, ROUND_SEED, TABLE_SEED, VAULT_SEED};
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeTableArgs {
+    pub minimum_bet_amount: u64,
+    pub round_period_ts: u64,
+    pub spin_timeout_ts: u64,
+    pub claim_window_secs: u64,
+    pub oracle_queue: Pubkey,
+    pub wheel: Wheel,
+    pub zero_rule: ZeroRule,
+    /// Bankroll the admin deposits into the vault, in lamports or tokens of the mint
+    pub initial_vault_amount: u64,
+}
 
 #[derive(Accounts)]
+#[instruction(table_id: u64)]
 pub struct InitializeTable<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = Table::DISCRIMINATOR.len() + Table::INIT_SPACE,
+        seeds = [TABLE_SEED, table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub table: Account<'info, Table>,
     /// CHECK: Vault for holding round bet amounts, system account
     #[account(
         mut,
-        seeds = [VAULT_SEED],
+        seeds = [VAULT_SEED, table.key().as_ref()],
         bump,
     )]
     pub vault: UncheckedAccount<'info>,
+    /// CHECK: Fee vault for holding the rake, system account
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, table.key().as_ref()],
+        bump,
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
     #[account(
         init,
         payer = admin,
-        space = Table::DISCRIMINATOR.len() + Table::INIT_SPACE,
-        seeds = [TABLE_SEED],
+        space = Round::DISCRIMINATOR.len() + Round::INIT_SPACE,
+        seeds = [ROUND_SEED, table.key().as_ref(), 1_u64.to_le_bytes().as_ref()],
         bump,
     )]
-    pub table: Account<'info, Table>,
+    pub round: Box<Account<'info, Round>>,
     #[account(
         init,
         payer = admin,
-        space = Round::DISCRIMINATOR.len() + Round::INIT_SPACE,
-        seeds = [ROUND_SEED, 1_u64.to_le_bytes().as_ref()],
+        space = History::DISCRIMINATOR.len() + std::mem::size_of::<History>(),
+        seeds = [HISTORY_SEED, table.key().as_ref()],
         bump,
     )]
-    pub round: Account<'info, Round>,
+    pub history: AccountLoader<'info, History>,
+    /// Mint bets are placed in, the table uses lamports when not provided
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// Vault token account for holding round bet amounts of token-denominated tables
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Fee vault token account for holding the rake of token-denominated tables
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Admin token account the initial bankroll of token-denominated tables is drawn from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program,
+    )]
+    pub admin_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -39,9 +107,20 @@ impl<'info> InitializeTable<'info> {
     pub fn handler(
         &mut self,
         bumps: &InitializeTableBumps,
-        minimum_bet_amount: u64,
-        round_period_ts: u64,
+        table_id: u64,
+        args: InitializeTableArgs,
     ) -> Result<()> {
+        let InitializeTableArgs {
+            minimum_bet_amount,
+            round_period_ts,
+            spin_timeout_ts,
+            claim_window_secs,
+            oracle_queue,
+            wheel,
+            zero_rule,
+            initial_vault_amount,
+        } = args;
+
         require!(
             minimum_bet_amount > 0,
             MagicRouletteError::InvalidMinimumBetAmount
@@ -49,38 +128,138 @@ impl<'info> InitializeTable<'info> {
 
         require!(round_period_ts > 0, MagicRouletteError::InvalidRoundPeriod);
 
+        require!(spin_timeout_ts > 0, MagicRouletteError::InvalidSpinTimeout);
+
+        require!(
+            claim_window_secs > 0,
+            MagicRouletteError::InvalidClaimWindow
+        );
+
+        require!(
+            oracle_queue != Pubkey::default(),
+            MagicRouletteError::InvalidAddress
+        );
+
+        let mint = self.mint.as_ref().map(|mint| mint.key());
+
+        require!(
+            mint.is_none()
+                || (self.vault_token_account.is_some() && self.fee_vault_token_account.is_some()),
+            MagicRouletteError::MissingTokenAccount
+        );
+
+        // extensions like transfer fees, permanent delegates or transfer hooks could leave the vault
+        // with less than the pool and exposure record, or keep winnings from being paid out
+        if let Some(mint) = &self.mint {
+            require!(
+                has_supported_extensions(&mint.to_account_info())?,
+                MagicRouletteError::UnsupportedMint
+            );
+        }
+
         let now = Clock::get()?.unix_timestamp;
 
         self.table.set_inner(Table {
+            table_id,
             admin: self.admin.key(),
             minimum_bet_amount,
+            // no limits until set by the admin, bets are still bounded by vault solvency
+            maximum_bet_amounts: [u64::MAX; BetKind::COUNT],
+            maximum_round_liability: u64::MAX,
             current_round_number: 1,
             next_round_ts: now + round_period_ts as i64,
             round_period_ts,
+            spin_timeout_ts,
+            claim_window_secs,
+            // cranking is unpaid until set by the admin
+            crank_fee: 0,
+            forfeited_winnings: 0,
+            unclaimed_liability: 0,
+            total_shares: 0,
+            lp_shares: 0,
+            lp_cooldown_secs: 0,
+            rake_bps: 0,
+            referral_bps: 0,
+            unclaimed_referral_rewards: 0,
+            oracle_queue,
+            mint,
+            payout_multipliers: BetKind::STANDARD_PAYOUT_MULTIPLIERS,
+            wheel,
+            zero_rule,
             bump: bumps.table,
             vault_bump: bumps.vault,
+            fee_vault_bump: bumps.fee_vault,
         });
 
-        self.round.set_inner(Round::new(1, bumps.round));
+        self.round.set_inner(Round::new(
+            1,
+            self.admin.key(),
+            bumps.round,
+            self.table.payout_multipliers,
+            self.table.zero_rule,
+        ));
+
+        let mut history = self.history.load_init()?;
+        history.table = self.table.key();
+        history.bump = bumps.history;
+        drop(history);
+
+        // token-denominated tables hold funds in token accounts, which are rent exempt on creation
+        if let Some(mint) = &self.mint {
+            if initial_vault_amount > 0 {
+                transfer_tokens(
+                    require_token_account(&self.token_program)?,
+                    mint,
+                    require_token_account(&self.admin_token_account)?.to_account_info(),
+                    require_token_account(&self.vault_token_account)?.to_account_info(),
+                    self.admin.to_account_info(),
+                    initial_vault_amount,
+                    &[],
+                )?;
+            }
+
+            return Ok(());
+        }
+
+        // vault is topped up to prevent it from being under-rent when winnings are first drawn,
+        // and the fee vault so that it can receive rake smaller than the rent
+        self.top_up_rent(self.vault.to_account_info())?;
+        self.top_up_rent(self.fee_vault.to_account_info())?;
+
+        if initial_vault_amount > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.admin.to_account_info(),
+                        to: self.vault.to_account_info(),
+                    },
+                ),
+                initial_vault_amount,
+            )?;
+        }
 
-        // transfer minimum system account rent to vault, to prevent it from being under-rent when winnings are first drawn
+        Ok(())
+    }
+
+    /// Transfers the minimum system account rent to `account`.
+    fn top_up_rent(&self, account: AccountInfo<'info>) -> Result<()> {
         let min_rent_lamports = Rent::get()?.minimum_balance(0);
-        let vault_lamports = self.vault.lamports();
-        let lamports_to_topup = min_rent_lamports
-            .checked_sub(vault_lamports)
-            .ok_or(MagicRouletteError::MathOverflow)?;
+        let lamports_to_topup = min_rent_lamports.saturating_sub(account.lamports());
+
+        if lamports_to_topup == 0 {
+            return Ok(());
+        }
 
         transfer(
             CpiContext::new(
                 self.system_program.to_account_info(),
                 Transfer {
                     from: self.admin.to_account_info(),
-                    to: self.vault.to_account_info(),
+                    to: account,
                 },
             ),
             lamports_to_topup,
-        )?;
-
-        Ok(())
+        )
     }
 }
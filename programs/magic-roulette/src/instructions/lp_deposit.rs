@@ -0,0 +1,136 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    error::MagicRouletteError, events::LpDeposited, utils::Vault, LpPosition, Round, Table,
+    LP_POSITION_SEED, MIN_HOUSE_SHARES, ROUND_SEED, TABLE_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct LpDeposit<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, table.key().as_ref()],
+        bump = table.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
+        bump = current_round.bump
+    )]
+    pub current_round: Box<Account<'info, Round>>,
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = LpPosition::DISCRIMINATOR.len() + LpPosition::INIT_SPACE,
+        seeds = [LP_POSITION_SEED, table.key().as_ref(), provider.key().as_ref()],
+        bump,
+    )]
+    pub lp_position: Account<'info, LpPosition>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LpDeposit<'info> {
+    pub fn handler(&mut self, bumps: &LpDepositBumps, amount: u64) -> Result<()> {
+        require!(
+            self.table.mint.is_none(),
+            MagicRouletteError::LpTokenTableUnsupported
+        );
+        require!(amount > 0, MagicRouletteError::InvalidAmount);
+
+        let vault_balance = self.vault_accounts().balance()?;
+        let bankroll = self.table.bankroll(vault_balance, &self.current_round);
+
+        // bankroll funded before the first provider joined belongs to the house
+        if self.table.total_shares == 0 {
+            self.table.total_shares = bankroll;
+        }
+
+        // an empty or tiny pool would let the first provider inflate the share price with a donation
+        require!(
+            self.table.house_shares() >= MIN_HOUSE_SHARES,
+            MagicRouletteError::HouseBankrollTooLow
+        );
+
+        let shares = LpPosition::shares_for_amount(amount, self.table.total_shares, bankroll)
+            .ok_or(MagicRouletteError::BankrollDepleted)?;
+
+        require!(shares > 0, MagicRouletteError::InvalidAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        if self.lp_position.provider == Pubkey::default() {
+            self.lp_position.set_inner(LpPosition {
+                provider: self.provider.key(),
+                shares: 0,
+                last_deposit_ts: now,
+                bump: bumps.lp_position,
+            });
+        }
+
+        self.lp_position.shares = self
+            .lp_position
+            .shares
+            .checked_add(shares)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+        self.lp_position.last_deposit_ts = now;
+
+        self.table.total_shares = self
+            .table
+            .total_shares
+            .checked_add(shares)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+        self.table.lp_shares = self
+            .table
+            .lp_shares
+            .checked_add(shares)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.provider.to_account_info(),
+                    to: self.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(LpDeposited {
+            table: self.table.key(),
+            provider: self.provider.key(),
+            amount,
+            shares,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Funds of the table, liquidity pools only back lamport-denominated tables.
+    fn vault_accounts(&mut self) -> Vault<'_, 'info> {
+        Vault {
+            table: &self.table,
+            vault: self.vault.to_account_info(),
+            vault_token_account: None,
+            mint: None,
+            token_program: None,
+            system_program: &self.system_program,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MagicRouletteError,
+    events::LpWithdrawn,
+    utils::{close, Vault},
+    LpPosition, Round, Table, LP_POSITION_SEED, ROUND_SEED, TABLE_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct LpWithdraw<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, table.key().as_ref()],
+        bump = table.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
+        bump = current_round.bump
+    )]
+    pub current_round: Box<Account<'info, Round>>,
+    #[account(
+        mut,
+        seeds = [LP_POSITION_SEED, table.key().as_ref(), provider.key().as_ref()],
+        bump = lp_position.bump,
+    )]
+    pub lp_position: Account<'info, LpPosition>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LpWithdraw<'info> {
+    pub fn handler(&mut self, shares: u64) -> Result<()> {
+        require!(shares > 0, MagicRouletteError::InvalidAmount);
+        require!(
+            shares <= self.lp_position.shares,
+            MagicRouletteError::InsufficientShares
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let withdrawable_ts = self
+            .lp_position
+            .last_deposit_ts
+            .checked_add(self.table.lp_cooldown_secs as i64)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        require!(now >= withdrawable_ts, MagicRouletteError::LpCooldownActive);
+
+        let vault_balance = self.vault_accounts().balance()?;
+        let bankroll = self.table.bankroll(vault_balance, &self.current_round);
+        let amount = LpPosition::amount_for_shares(shares, self.table.total_shares, bankroll)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        // vault must still cover unclaimed winnings and the worst outcome of the current round
+        let available_amount = self.table.free_balance(vault_balance, &self.current_round);
+
+        require!(
+            amount <= available_amount,
+            MagicRouletteError::VaultNotWithdrawable
+        );
+
+        self.lp_position.shares -= shares;
+        self.table.total_shares -= shares;
+        self.table.lp_shares -= shares;
+
+        let provider = self.provider.to_account_info();
+
+        self.vault_accounts().transfer_out(provider, amount)?;
+
+        // return the rent once every share is withdrawn
+        if self.lp_position.shares == 0 {
+            close(
+                self.lp_position.to_account_info(),
+                self.provider.to_account_info(),
+            )?;
+        }
+
+        emit!(LpWithdrawn {
+            table: self.table.key(),
+            provider: self.provider.key(),
+            amount,
+            shares,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Funds of the table, liquidity pools only back lamport-denominated tables.
+    fn vault_accounts(&mut self) -> Vault<'_, 'info> {
+        Vault {
+            table: &self.table,
+            vault: self.vault.to_account_info(),
+            vault_token_account: None,
+            mint: None,
+            token_program: None,
+            system_program: &self.system_program,
+        }
+    }
+}
@@ -1,15 +1,37 @@
 pub mod advance_round;
+pub mod carry_imprisoned_stake;
+pub mod claim_referral_rewards;
 pub mod claim_winnings;
+pub mod close_losing_bet;
+pub mod close_round;
 pub mod initialize_table;
+pub mod lp_deposit;
+pub mod lp_withdraw;
 pub mod place_bet;
+pub mod register_referral;
+pub mod retry_spin;
+pub mod settle_bets;
 pub mod spin_roulette;
+pub mod sweep_expired;
 pub mod update_table;
+pub mod withdraw_fees;
 pub mod withdraw_vault;
 
 pub use advance_round::*;
+pub use carry_imprisoned_stake::*;
+pub use claim_referral_rewards::*;
 pub use claim_winnings::*;
+pub use close_losing_bet::*;
+pub use close_round::*;
 pub use initialize_table::*;
+pub use lp_deposit::*;
+pub use lp_withdraw::*;
 pub use place_bet::*;
+pub use register_referral::*;
+pub use retry_spin::*;
+pub use settle_bets::*;
 pub use spin_roulette::*;
+pub use sweep_expired::*;
 pub use update_table::*;
+pub use withdraw_fees::*;
 pub use withdraw_vault::*;
@@ -1,58 +1,111 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::error::MagicRouletteError;
 use crate::events::BetPlaced;
-use crate::{Bet, BetType, Round, Table, BET_SEED, ROUND_SEED, TABLE_SEED, VAULT_SEED};
+use crate::utils::{require_token_account, transfer_tokens, Vault};
+use crate::{
+    Bet, PlayerStats, Position, Referral, Round, Table, BET_SEED, FEE_VAULT_SEED,
+    PLAYER_STATS_SEED, REFERRAL_SEED, ROUND_SEED, TABLE_SEED, VAULT_SEED,
+};
 
 #[derive(Accounts)]
-#[instruction(bet_type: BetType)]
 pub struct PlaceBet<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
     #[account(
         mut,
-        seeds = [VAULT_SEED],
+        seeds = [VAULT_SEED, table.key().as_ref()],
         bump = table.vault_bump
     )]
     pub vault: SystemAccount<'info>,
     #[account(
-        seeds = [TABLE_SEED],
+        mut,
+        seeds = [FEE_VAULT_SEED, table.key().as_ref()],
+        bump = table.fee_vault_bump
+    )]
+    pub fee_vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
         bump = table.bump
     )]
     pub table: Account<'info, Table>,
     #[account(
         mut,
-        seeds = [ROUND_SEED, table.current_round_number.to_le_bytes().as_ref()],
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
         bump = round.bump
     )]
-    pub round: Account<'info, Round>,
+    pub round: Box<Account<'info, Round>>,
     #[account(
-        init,
+        init_if_needed,
         payer = player,
         space = Bet::DISCRIMINATOR.len() + Bet::INIT_SPACE,
         seeds = [
             BET_SEED,
+            table.key().as_ref(),
             round.key().as_ref(),
             player.key().as_ref()
         ],
         bump,
     )]
     pub bet: Account<'info, Bet>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerStats::DISCRIMINATOR.len() + PlayerStats::INIT_SPACE,
+        seeds = [
+            PLAYER_STATS_SEED,
+            player.key().as_ref(),
+            table.mint.unwrap_or_default().as_ref()
+        ],
+        bump,
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+    /// Referral of the account that referred the player, if any
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, table.key().as_ref(), referral.referrer.as_ref()],
+        bump = referral.bump,
+        constraint = referral.referrer != player.key() @ MagicRouletteError::SelfReferral
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+    #[account(constraint = table.mint == Some(mint.key()) @ MagicRouletteError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = player,
+        token::token_program = token_program,
+    )]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> PlaceBet<'info> {
-    pub fn handler(
-        &mut self,
-        bumps: &PlaceBetBumps,
-        bet_type: BetType,
-        bet_amount: u64,
-    ) -> Result<()> {
-        require!(
-            bet_amount >= self.table.minimum_bet_amount,
-            MagicRouletteError::InvalidBetAmount
-        );
+    /// Places every position in one go, shared by `place_bet` and `place_bets`.
+    pub fn handler(&mut self, bumps: &PlaceBetBumps, positions: Vec<Position>) -> Result<()> {
+        require!(!positions.is_empty(), MagicRouletteError::NoPositions);
 
         let now = Clock::get()?.unix_timestamp;
 
@@ -61,44 +114,231 @@ impl<'info> PlaceBet<'info> {
             MagicRouletteError::RoundOver
         );
 
-        require!(bet_type.is_valid(), MagicRouletteError::InvalidBetType);
-
-        self.bet.set_inner(Bet {
-            player: self.player.key(),
-            round: self.round.key(),
-            amount: bet_amount,
-            bump: bumps.bet,
-            bet_type,
-            is_claimed: false,
-        });
-
-        transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                Transfer {
-                    from: self.player.to_account_info(),
-                    to: self.vault.to_account_info(),
-                },
-            ),
-            bet_amount,
+        // bet is shared by all positions of the player in this round, so only set it up once
+        if self.bet.player == Pubkey::default() {
+            self.bet.set_inner(Bet {
+                player: self.player.key(),
+                round: self.round.key(),
+                amount: 0,
+                bump: bumps.bet,
+                is_claimed: false,
+                is_carried: false,
+                positions: Vec::with_capacity(positions.len()),
+                imprisoned_positions: Vec::new(),
+            });
+
+            self.round.bet_count = self
+                .round
+                .bet_count
+                .checked_add(1)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+        }
+
+        require!(
+            self.bet.positions.len() + positions.len() <= Bet::MAX_POSITIONS,
+            MagicRouletteError::TooManyPositions
+        );
+
+        let mut total_amount: u64 = 0;
+        let mut total_rake: u64 = 0;
+        let mut total_referral_reward: u64 = 0;
+
+        for position in positions.iter() {
+            require!(
+                position.amount >= self.table.minimum_bet_amount,
+                MagicRouletteError::InvalidBetAmount
+            );
+
+            // stakes on the same bet type add up, so splitting one across positions can't get around the maximum
+            let bet_type_amount = self
+                .bet
+                .positions
+                .iter()
+                .chain(positions.iter())
+                .filter(|placed| placed.bet_type == position.bet_type)
+                .try_fold(0_u64, |amount, placed| amount.checked_add(placed.amount))
+                .ok_or(MagicRouletteError::MathOverflow)?;
+
+            require!(
+                bet_type_amount
+                    <= self.table.maximum_bet_amounts[position.bet_type.kind() as usize],
+                MagicRouletteError::BetAboveMaximum
+            );
+            require!(
+                position.bet_type.is_valid(self.table.wheel),
+                MagicRouletteError::InvalidBetType
+            );
+
+            self.round
+                .add_exposure(position, self.table.wheel)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+
+            total_amount = total_amount
+                .checked_add(position.amount)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+            total_rake = total_rake
+                .checked_add(
+                    self.table
+                        .rake(position.amount)
+                        .ok_or(MagicRouletteError::MathOverflow)?,
+                )
+                .ok_or(MagicRouletteError::MathOverflow)?;
+            total_referral_reward = total_referral_reward
+                .checked_add(
+                    self.table
+                        .referral_reward(position.amount)
+                        .ok_or(MagicRouletteError::MathOverflow)?,
+                )
+                .ok_or(MagicRouletteError::MathOverflow)?;
+        }
+
+        self.bet.amount = self
+            .bet
+            .amount
+            .checked_add(total_amount)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+        self.bet.positions.extend_from_slice(&positions);
+
+        // stats are created with the first bet of the player on any table of the same denomination
+        if self.player_stats.player == Pubkey::default() {
+            self.player_stats.set_inner(PlayerStats {
+                player: self.player.key(),
+                mint: self.table.mint.unwrap_or_default(),
+                total_wagered: 0,
+                total_won: 0,
+                bets_placed: 0,
+                wins: 0,
+                biggest_win: 0,
+                bump: bumps.player_stats,
+            });
+        }
+
+        self.player_stats
+            .record_bet(total_amount, positions.len() as u64)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        // single transfer for all positions
+        self.transfer_from_player(
+            self.vault.to_account_info(),
+            &self.vault_token_account,
+            total_amount,
         )?;
 
+        // rake is charged on top of the stakes, so payouts are unaffected
+        if total_rake > 0 {
+            self.transfer_from_player(
+                self.fee_vault.to_account_info(),
+                &self.fee_vault_token_account,
+                total_rake,
+            )?;
+        }
+
+        // referrer is credited out of the rake that was just collected
+        if let Some(referral) = self.referral.as_mut() {
+            referral.accrued_rewards = referral
+                .accrued_rewards
+                .checked_add(total_referral_reward)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+            referral.total_rewards = referral
+                .total_rewards
+                .checked_add(total_referral_reward)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+
+            self.table.unclaimed_referral_rewards = self
+                .table
+                .unclaimed_referral_rewards
+                .checked_add(total_referral_reward)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+        }
+
         self.round.pool_amount = self
             .round
             .pool_amount
-            .checked_add(bet_amount)
+            .checked_add(total_amount)
             .ok_or(MagicRouletteError::MathOverflow)?;
 
-        let now = Clock::get()?.unix_timestamp;
+        let max_exposure = self.round.max_exposure();
 
-        emit!(BetPlaced {
-            bet_amount,
-            bet_type,
-            player: self.player.key(),
-            round: self.round.key(),
-            timestamp: now,
-        });
+        require!(
+            max_exposure <= self.table.maximum_round_liability,
+            MagicRouletteError::RoundLiabilityExceeded
+        );
+
+        // vault already holds the stakes, so it must be able to pay out the worst outcome on its own,
+        // without touching what is still owed to winners of settled rounds
+        let payable_amount = self
+            .vault_accounts()
+            .balance()?
+            .saturating_sub(self.table.unclaimed_liability);
+
+        require!(
+            max_exposure <= payable_amount,
+            MagicRouletteError::RoundInsolvent
+        );
+
+        let referrer = self.referral.as_ref().map(|referral| referral.referrer);
+
+        for position in positions {
+            let rake = self
+                .table
+                .rake(position.amount)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+
+            emit!(BetPlaced {
+                table: self.table.key(),
+                bet_amount: position.amount,
+                bet_type: position.bet_type,
+                player: self.player.key(),
+                round: self.round.key(),
+                rake,
+                referrer,
+                timestamp: now,
+            });
+        }
 
         Ok(())
     }
+
+    /// Moves `amount` from the player into `vault`, or into `vault_token_account` for token-denominated tables.
+    fn transfer_from_player(
+        &self,
+        vault: AccountInfo<'info>,
+        vault_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<()> {
+        if self.table.mint.is_none() {
+            return transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.player.to_account_info(),
+                        to: vault,
+                    },
+                ),
+                amount,
+            );
+        }
+
+        transfer_tokens(
+            require_token_account(&self.token_program)?,
+            require_token_account(&self.mint)?,
+            require_token_account(&self.player_token_account)?.to_account_info(),
+            require_token_account(vault_token_account)?.to_account_info(),
+            self.player.to_account_info(),
+            amount,
+            &[],
+        )
+    }
+
+    /// Funds of the table, held in the vault token account for token-denominated tables.
+    fn vault_accounts(&mut self) -> Vault<'_, 'info> {
+        Vault {
+            table: &self.table,
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.as_mut(),
+            mint: self.mint.as_ref(),
+            token_program: self.token_program.as_ref(),
+            system_program: &self.system_program,
+        }
+    }
 }
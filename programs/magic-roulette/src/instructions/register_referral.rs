@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{Referral, Table, REFERRAL_SEED, TABLE_SEED};
+
+#[derive(Accounts)]
+pub struct RegisterReferral<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    #[account(
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        init,
+        payer = referrer,
+        space = Referral::DISCRIMINATOR.len() + Referral::INIT_SPACE,
+        seeds = [REFERRAL_SEED, table.key().as_ref(), referrer.key().as_ref()],
+        bump,
+    )]
+    pub referral: Account<'info, Referral>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterReferral<'info> {
+    pub fn handler(&mut self, bumps: &RegisterReferralBumps) -> Result<()> {
+        self.referral.set_inner(Referral {
+            referrer: self.referrer.key(),
+            accrued_rewards: 0,
+            total_rewards: 0,
+            bump: bumps.referral,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use std::ops::Add;
+
+use anchor_lang::prelude::*;
+use ephemeral_vrf_sdk::anchor::vrf;
+
+use crate::events::SpinRetried;
+use crate::utils::{create_spin_randomness_ix, spin_caller_seed};
+use crate::{
+    error::MagicRouletteError, History, Round, Table, HISTORY_SEED, ROUND_SEED, TABLE_SEED,
+};
+
+#[vrf]
+#[derive(Accounts)]
+pub struct RetrySpin<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
+        bump = current_round.bump
+    )]
+    pub current_round: Box<Account<'info, Round>>,
+    // already initialized by spin_roulette
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.add(1).to_le_bytes().as_ref()
+        ],
+        bump = new_round.bump,
+    )]
+    pub new_round: Box<Account<'info, Round>>,
+    #[account(
+        seeds = [HISTORY_SEED, table.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: AccountLoader<'info, History>,
+    /// CHECK: Oracle queue configured on the table
+    #[account(
+        mut,
+        address = table.oracle_queue @ MagicRouletteError::InvalidQueue
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+}
+
+impl<'info> RetrySpin<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        require!(self.current_round.is_spun, MagicRouletteError::RoundNotSpun);
+
+        let now = Clock::get()?.unix_timestamp;
+        let retry_ts = self
+            .current_round
+            .spun_ts
+            .checked_add(self.table.spin_timeout_ts as i64)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        // only re-request randomness once the previous request is considered lost
+        require!(now >= retry_ts, MagicRouletteError::SpinNotTimedOut);
+
+        self.current_round.spun_ts = now;
+
+        let seed = spin_caller_seed(
+            &self.table.key(),
+            &self.current_round,
+            &self.slot_hashes,
+            now,
+        )?;
+
+        self.current_round.caller_seed = seed;
+
+        let ix = create_spin_randomness_ix(
+            self.payer.key(),
+            self.oracle_queue.key(),
+            self.table.key(),
+            self.current_round.key(),
+            self.new_round.key(),
+            self.history.key(),
+            seed,
+        );
+
+        self.invoke_signed_vrf(&self.payer.to_account_info(), &ix)?;
+
+        emit!(SpinRetried {
+            table: self.table.key(),
+            round: self.current_round.key(),
+            round_number: self.current_round.round_number,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::MagicRouletteError,
+    events::BetsSettled,
+    utils::{close, require_token_account, Vault},
+    Bet, PlayerStats, Round, Table, BET_SEED, ID, PLAYER_STATS_SEED, ROUND_SEED, TABLE_SEED,
+    VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SettleBets<'info> {
+    /// Anyone can crank settlement, receives the crank fee of the table
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, table.key().as_ref()],
+        bump = table.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            round.round_number.to_le_bytes().as_ref()
+        ],
+        bump = round.bump
+    )]
+    pub round: Box<Account<'info, Round>>,
+    /// Open round of the table, whose worst outcome the vault must keep covering
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
+        bump = current_round.bump
+    )]
+    pub current_round: Box<Account<'info, Round>>,
+    #[account(constraint = table.mint == Some(mint.key()) @ MagicRouletteError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = cranker,
+        token::token_program = token_program,
+    )]
+    pub cranker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleBets<'info> {
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, SettleBets<'info>>) -> Result<()> {
+        let accounts = ctx.accounts;
+
+        let table_key = accounts.table.key();
+        let round_key = accounts.round.key();
+        let denomination = accounts.table.mint.unwrap_or_default();
+        let now = Clock::get()?.unix_timestamp;
+
+        let outcome = accounts
+            .round
+            .outcome
+            .ok_or(MagicRouletteError::RoundAwaitingOutcome)?;
+
+        // expired bets are left for the admin to sweep
+        require!(
+            !accounts
+                .round
+                .is_claim_expired(now, accounts.table.claim_window_secs),
+            MagicRouletteError::ClaimWindowExpired
+        );
+
+        // token-denominated tables also need the token account of each player to pay out to
+        let chunk_size = if accounts.table.mint.is_some() { 4 } else { 3 };
+
+        require!(
+            ctx.remaining_accounts.len().is_multiple_of(chunk_size),
+            MagicRouletteError::InsufficientRemainingAccounts
+        );
+
+        let mut winnings: u64 = 0;
+        let mut settled_count: u64 = 0;
+        let mut paid_count: u64 = 0;
+
+        // settle each (bet, player, player stats) triple, followed by the player token account if needed
+        for chunk in ctx.remaining_accounts.chunks(chunk_size) {
+            let (bet_account, player_account, player_stats_account) =
+                (&chunk[0], &chunk[1], &chunk[2]);
+
+            let mut bet = Bet::try_deserialize(&mut &bet_account.data.borrow_mut()[..])?;
+            let player_key = player_account.key();
+            let bet_seeds = &[
+                BET_SEED,
+                table_key.as_ref(),
+                round_key.as_ref(),
+                player_key.as_ref(),
+                &[bet.bump],
+            ];
+            let bet_pda = Pubkey::create_program_address(bet_seeds, &ID).unwrap();
+
+            require!(bet_pda == bet_account.key(), MagicRouletteError::InvalidBet);
+            // settled bets are closed or marked claimed, so the crank fee is only paid once per bet
+            require!(!bet.is_claimed, MagicRouletteError::WinningsAlreadyClaimed);
+
+            let bet_winnings = bet
+                .winnings(
+                    outcome,
+                    &accounts.round.payout_multipliers,
+                    accounts.round.zero_rule,
+                )
+                .ok_or(MagicRouletteError::MathOverflow)?;
+
+            if bet_winnings > 0 {
+                let mut player_stats =
+                    PlayerStats::try_deserialize(&mut &player_stats_account.data.borrow()[..])?;
+                let player_stats_seeds = &[
+                    PLAYER_STATS_SEED,
+                    player_key.as_ref(),
+                    denomination.as_ref(),
+                    &[player_stats.bump],
+                ];
+                let player_stats_pda =
+                    Pubkey::create_program_address(player_stats_seeds, &ID).unwrap();
+
+                require!(
+                    player_stats_pda == player_stats_account.key(),
+                    MagicRouletteError::InvalidAddress
+                );
+
+                player_stats
+                    .record_win(bet_winnings, bet.winning_positions(outcome))
+                    .ok_or(MagicRouletteError::MathOverflow)?;
+
+                let mut data = player_stats_account.try_borrow_mut_data()?;
+                player_stats.serialize(&mut &mut data[PlayerStats::DISCRIMINATOR.len()..])?;
+                drop(data);
+
+                let destination = match chunk.get(3) {
+                    Some(player_token_account) => {
+                        let token_account = TokenAccount::try_deserialize(
+                            &mut &player_token_account.data.borrow()[..],
+                        )?;
+
+                        require!(
+                            token_account.owner == player_key,
+                            MagicRouletteError::InvalidAddress
+                        );
+
+                        player_token_account.clone()
+                    }
+                    None => player_account.clone(),
+                };
+
+                accounts
+                    .vault_accounts()
+                    .transfer_out(destination, bet_winnings)?;
+
+                paid_count += 1;
+            }
+
+            winnings = winnings
+                .checked_add(bet_winnings)
+                .ok_or(MagicRouletteError::MathOverflow)?;
+            bet.is_claimed = true;
+
+            // bet is kept open only while a stake held en prison can still be carried
+            if bet.is_awaiting_carry(outcome, accounts.round.zero_rule) {
+                let mut data = bet_account.try_borrow_mut_data()?;
+                bet.serialize(&mut &mut data[Bet::DISCRIMINATOR.len()..])?;
+            } else {
+                close(bet_account.clone(), player_account.clone())?;
+
+                accounts.round.bet_count = accounts.round.bet_count.saturating_sub(1);
+            }
+
+            settled_count += 1;
+        }
+
+        accounts.table.unclaimed_liability = accounts
+            .table
+            .unclaimed_liability
+            .checked_sub(winnings)
+            .ok_or(MagicRouletteError::LiabilityUnderflow)?;
+
+        // crank fee is paid by the house, so it never touches amounts owed to players or the worst
+        // outcome of the current round, and only for bets paid out, so cranking losing bets of your
+        // own can't farm it
+        let vault_balance = accounts.vault_accounts().balance()?;
+        let payable_amount = accounts
+            .table
+            .free_balance(vault_balance, &accounts.current_round);
+        let crank_fee = accounts
+            .table
+            .crank_fee
+            .checked_mul(paid_count)
+            .ok_or(MagicRouletteError::MathOverflow)?
+            .min(payable_amount);
+
+        if crank_fee > 0 {
+            let destination = match accounts.table.mint {
+                Some(_) => {
+                    require_token_account(&accounts.cranker_token_account)?.to_account_info()
+                }
+                None => accounts.cranker.to_account_info(),
+            };
+
+            accounts
+                .vault_accounts()
+                .transfer_out(destination, crank_fee)?;
+        }
+
+        emit!(BetsSettled {
+            table: table_key,
+            round: round_key,
+            cranker: accounts.cranker.key(),
+            settled_count,
+            winnings,
+            crank_fee,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Funds of the table, held in the vault token account for token-denominated tables.
+    fn vault_accounts(&mut self) -> Vault<'_, 'info> {
+        Vault {
+            table: &self.table,
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.as_mut(),
+            mint: self.mint.as_ref(),
+            token_program: self.token_program.as_ref(),
+            system_program: &self.system_program,
+        }
+    }
+}
@@ -2,13 +2,12 @@ use std::ops::Add;
 
 use anchor_lang::prelude::*;
 use ephemeral_vrf_sdk::anchor::vrf;
-use ephemeral_vrf_sdk::consts::DEFAULT_QUEUE;
-use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
-use ephemeral_vrf_sdk::types::SerializableAccountMeta;
 
 use crate::events::RouletteSpun;
-use crate::{error::MagicRouletteError, Round, Table, ROUND_SEED, TABLE_SEED};
-use crate::{instruction, ID};
+use crate::utils::{create_spin_randomness_ix, spin_caller_seed};
+use crate::{
+    error::MagicRouletteError, History, Round, Table, HISTORY_SEED, ROUND_SEED, TABLE_SEED,
+};
 
 #[vrf]
 #[derive(Accounts)]
@@ -16,28 +15,41 @@ pub struct SpinRoulette<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
-        seeds = [TABLE_SEED],
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
         bump = table.bump
     )]
     pub table: Account<'info, Table>,
     #[account(
         mut,
-        seeds = [ROUND_SEED, table.current_round_number.to_le_bytes().as_ref()],
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
         bump = current_round.bump
     )]
-    pub current_round: Account<'info, Round>,
+    pub current_round: Box<Account<'info, Round>>,
     #[account(
         init,
         payer = payer,
         space = Round::DISCRIMINATOR.len() + Round::INIT_SPACE,
-        seeds = [ROUND_SEED, table.current_round_number.add(1).to_le_bytes().as_ref()],
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.add(1).to_le_bytes().as_ref()
+        ],
         bump,
     )]
-    pub new_round: Account<'info, Round>,
-    /// CHECK: MagicBlock default queue
+    pub new_round: Box<Account<'info, Round>>,
+    #[account(
+        seeds = [HISTORY_SEED, table.key().as_ref()],
+        bump = history.load()?.bump,
+    )]
+    pub history: AccountLoader<'info, History>,
+    /// CHECK: Oracle queue configured on the table
     #[account(
         mut,
-        address = DEFAULT_QUEUE @ MagicRouletteError::InvalidQueue
+        address = table.oracle_queue @ MagicRouletteError::InvalidQueue
     )]
     pub oracle_queue: UncheckedAccount<'info>,
 }
@@ -51,52 +63,50 @@ impl<'info> SpinRoulette<'info> {
             MagicRouletteError::RoundNotReadyToSpin
         );
 
+        require!(
+            !self.current_round.is_spun,
+            MagicRouletteError::RoundAlreadySpun
+        );
+
         self.current_round.is_spun = true;
 
         // new_round can't be initialized in callback, so it's initialized here
         self.new_round.set_inner(Round::new(
             self.table.current_round_number + 1,
+            self.payer.key(),
             bumps.new_round,
+            self.table.payout_multipliers,
+            self.table.zero_rule,
         ));
 
-        let seed = self.current_round.round_number as u8;
+        self.current_round.spun_ts = now;
 
-        let ix = create_request_randomness_ix(RequestRandomnessParams {
-            payer: self.payer.key(),
-            oracle_queue: self.oracle_queue.key(),
-            callback_program_id: ID,
-            callback_discriminator: instruction::AdvanceRound::DISCRIMINATOR.to_vec(),
-            caller_seed: [seed; 32],
-            accounts_metas: Some(vec![
-                SerializableAccountMeta {
-                    pubkey: self.table.key(),
-                    is_signer: false,
-                    is_writable: true,
-                },
-                SerializableAccountMeta {
-                    pubkey: self.current_round.key(),
-                    is_signer: false,
-                    is_writable: true,
-                },
-                SerializableAccountMeta {
-                    pubkey: self.new_round.key(),
-                    is_signer: false,
-                    is_writable: true,
-                },
-                SerializableAccountMeta {
-                    pubkey: self.system_program.key(),
-                    is_signer: false,
-                    is_writable: false,
-                },
-            ]),
-            ..Default::default()
-        });
+        let seed = spin_caller_seed(
+            &self.table.key(),
+            &self.current_round,
+            &self.slot_hashes,
+            now,
+        )?;
+
+        self.current_round.caller_seed = seed;
+
+        let ix = create_spin_randomness_ix(
+            self.payer.key(),
+            self.oracle_queue.key(),
+            self.table.key(),
+            self.current_round.key(),
+            self.new_round.key(),
+            self.history.key(),
+            seed,
+        );
 
         self.invoke_signed_vrf(&self.payer.to_account_info(), &ix)?;
 
         emit!(RouletteSpun {
+            table: self.table.key(),
             round: self.current_round.key(),
             round_number: self.current_round.round_number,
+            max_exposure: self.current_round.max_exposure(),
             timestamp: now,
         });
 
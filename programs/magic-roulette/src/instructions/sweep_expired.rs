@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::MagicRouletteError, events::WinningsSwept, Bet, Round, Table, BET_SEED, ROUND_SEED,
+    TABLE_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SweepExpired<'info> {
+    pub admin: Signer<'info>,
+    /// Player who placed the bet, receives the rent back
+    #[account(mut)]
+    pub player: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump,
+        has_one = admin @ MagicRouletteError::UnauthorizedAdmin
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            round.round_number.to_le_bytes().as_ref()
+        ],
+        bump = round.bump
+    )]
+    pub round: Box<Account<'info, Round>>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            BET_SEED,
+            table.key().as_ref(),
+            round.key().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = bet.bump,
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+impl<'info> SweepExpired<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            self.round
+                .is_claim_expired(now, self.table.claim_window_secs),
+            MagicRouletteError::ClaimWindowOpen
+        );
+
+        // round is settled, so its outcome is known at this point
+        let outcome = self
+            .round
+            .outcome
+            .ok_or(MagicRouletteError::RoundAwaitingOutcome)?;
+
+        // a claimed bet is only left open while its imprisoned stake waits to be carried
+        let unclaimed_winnings = if self.bet.is_claimed {
+            0
+        } else {
+            self.bet
+                .winnings(
+                    outcome,
+                    &self.round.payout_multipliers,
+                    self.round.zero_rule,
+                )
+                .ok_or(MagicRouletteError::MathOverflow)?
+        };
+        let imprisoned_stake = if self.bet.is_awaiting_carry(outcome, self.round.zero_rule) {
+            self.bet
+                .imprisoned_stake()
+                .ok_or(MagicRouletteError::MathOverflow)?
+        } else {
+            0
+        };
+        let winnings = unclaimed_winnings
+            .checked_add(imprisoned_stake)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        require!(winnings > 0, MagicRouletteError::BetNotWinning);
+
+        // winnings stay in the vault and are no longer owed to the player
+        self.table.forfeited_winnings = self
+            .table
+            .forfeited_winnings
+            .checked_add(winnings)
+            .ok_or(MagicRouletteError::MathOverflow)?;
+        self.table.unclaimed_liability = self
+            .table
+            .unclaimed_liability
+            .checked_sub(winnings)
+            .ok_or(MagicRouletteError::LiabilityUnderflow)?;
+        self.round.bet_count = self.round.bet_count.saturating_sub(1);
+
+        emit!(WinningsSwept {
+            table: self.table.key(),
+            player: self.player.key(),
+            round: self.round.key(),
+            winnings,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,28 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::MagicRouletteError, events::TableUpdated, Table, TABLE_SEED};
+use crate::{
+    error::MagicRouletteError, events::TableUpdated, BetKind, Table, ZeroRule, MAX_RAKE_BPS,
+    TABLE_SEED,
+};
+
+/// Table settings to change, left untouched when `None`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateTableArgs {
+    pub minimum_bet_amount: Option<u64>,
+    pub maximum_bet_amounts: Option<[u64; BetKind::COUNT]>,
+    pub maximum_round_liability: Option<u64>,
+    pub round_period_ts: Option<u64>,
+    pub spin_timeout_ts: Option<u64>,
+    pub claim_window_secs: Option<u64>,
+    pub crank_fee: Option<u64>,
+    pub lp_cooldown_secs: Option<u64>,
+    pub rake_bps: Option<u16>,
+    pub referral_bps: Option<u16>,
+    pub oracle_queue: Option<Pubkey>,
+    pub payout_multipliers: Option<[u8; BetKind::COUNT]>,
+    pub zero_rule: Option<ZeroRule>,
+    pub new_admin: Option<Pubkey>,
+}
 
 #[derive(Accounts)]
 pub struct UpdateTable<'info> {
@@ -8,7 +30,7 @@ pub struct UpdateTable<'info> {
     pub admin: Signer<'info>,
     #[account(
         mut,
-        seeds = [TABLE_SEED],
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
         bump = table.bump,
         has_one = admin @ MagicRouletteError::UnauthorizedAdmin
     )]
@@ -16,12 +38,24 @@ pub struct UpdateTable<'info> {
 }
 
 impl<'info> UpdateTable<'info> {
-    pub fn handler(
-        &mut self,
-        minimum_bet_amount: Option<u64>,
-        round_period_ts: Option<u64>,
-        new_admin: Option<Pubkey>,
-    ) -> Result<()> {
+    pub fn handler(&mut self, args: UpdateTableArgs) -> Result<()> {
+        let UpdateTableArgs {
+            minimum_bet_amount,
+            maximum_bet_amounts,
+            maximum_round_liability,
+            round_period_ts,
+            spin_timeout_ts,
+            claim_window_secs,
+            crank_fee,
+            lp_cooldown_secs,
+            rake_bps,
+            referral_bps,
+            oracle_queue,
+            payout_multipliers,
+            zero_rule,
+            new_admin,
+        } = args;
+
         if let Some(minimum_bet_amount) = minimum_bet_amount {
             require!(
                 minimum_bet_amount > 0,
@@ -31,12 +65,107 @@ impl<'info> UpdateTable<'info> {
             self.table.minimum_bet_amount = minimum_bet_amount;
         }
 
+        if let Some(maximum_bet_amounts) = maximum_bet_amounts {
+            self.table.maximum_bet_amounts = maximum_bet_amounts;
+        }
+
+        // checked after both are applied, so the bet range can be moved in a single update
+        require!(
+            Table::is_valid_bet_range(
+                self.table.minimum_bet_amount,
+                &self.table.maximum_bet_amounts
+            ),
+            MagicRouletteError::InvalidMaximumBetAmount
+        );
+
+        if let Some(maximum_round_liability) = maximum_round_liability {
+            require!(
+                maximum_round_liability > 0,
+                MagicRouletteError::InvalidMaximumRoundLiability
+            );
+
+            self.table.maximum_round_liability = maximum_round_liability;
+        }
+
         if let Some(round_period_ts) = round_period_ts {
             require!(round_period_ts > 0, MagicRouletteError::InvalidRoundPeriod);
 
             self.table.round_period_ts = round_period_ts;
         }
 
+        if let Some(spin_timeout_ts) = spin_timeout_ts {
+            require!(spin_timeout_ts > 0, MagicRouletteError::InvalidSpinTimeout);
+
+            self.table.spin_timeout_ts = spin_timeout_ts;
+        }
+
+        if let Some(claim_window_secs) = claim_window_secs {
+            require!(
+                claim_window_secs > 0,
+                MagicRouletteError::InvalidClaimWindow
+            );
+
+            self.table.claim_window_secs = claim_window_secs;
+        }
+
+        if let Some(crank_fee) = crank_fee {
+            self.table.crank_fee = crank_fee;
+        }
+
+        // checked after both are applied, the crank fee is bounded by the minimum bet amount
+        let max_crank_fee = self
+            .table
+            .max_crank_fee()
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+        require!(
+            self.table.crank_fee <= max_crank_fee,
+            MagicRouletteError::InvalidCrankFee
+        );
+
+        if let Some(lp_cooldown_secs) = lp_cooldown_secs {
+            self.table.lp_cooldown_secs = lp_cooldown_secs;
+        }
+
+        if let Some(rake_bps) = rake_bps {
+            require!(rake_bps <= MAX_RAKE_BPS, MagicRouletteError::InvalidRakeBps);
+
+            self.table.rake_bps = rake_bps;
+        }
+
+        if let Some(referral_bps) = referral_bps {
+            self.table.referral_bps = referral_bps;
+        }
+
+        // checked after both are applied, referral rewards are paid out of the rake
+        require!(
+            self.table.referral_bps <= self.table.rake_bps,
+            MagicRouletteError::InvalidReferralBps
+        );
+
+        if let Some(oracle_queue) = oracle_queue {
+            require!(
+                oracle_queue != Pubkey::default(),
+                MagicRouletteError::InvalidAddress
+            );
+
+            self.table.oracle_queue = oracle_queue;
+        }
+
+        if let Some(payout_multipliers) = payout_multipliers {
+            require!(
+                BetKind::is_valid_payout_schedule(&payout_multipliers, self.table.wheel),
+                MagicRouletteError::InvalidPayoutMultipliers
+            );
+
+            // rounds already opened keep paying with the multipliers they were opened with
+            self.table.payout_multipliers = payout_multipliers;
+        }
+
+        if let Some(zero_rule) = zero_rule {
+            self.table.zero_rule = zero_rule;
+        }
+
         if let Some(new_admin) = new_admin {
             require!(
                 new_admin != Pubkey::default(),
@@ -49,9 +178,21 @@ impl<'info> UpdateTable<'info> {
         let now = Clock::get()?.unix_timestamp;
 
         emit!(TableUpdated {
+            table: self.table.key(),
             minimum_bet_amount: minimum_bet_amount,
+            maximum_bet_amounts,
+            maximum_round_liability,
             new_admin: new_admin,
             round_period_ts: round_period_ts,
+            spin_timeout_ts,
+            claim_window_secs,
+            crank_fee,
+            lp_cooldown_secs,
+            rake_bps,
+            referral_bps,
+            oracle_queue,
+            payout_multipliers,
+            zero_rule,
             timestamp: now,
         });
 
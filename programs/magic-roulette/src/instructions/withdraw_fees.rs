@@ -0,0 +1,116 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::MagicRouletteError,
+    events::FeesWithdrawn,
+    utils::{require_token_account, transfer_tokens},
+    Table, FEE_VAULT_SEED, TABLE_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: Fee vault for holding the rake, system account
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, table.key().as_ref()],
+        bump = table.fee_vault_bump
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+    #[account(
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
+        bump = table.bump,
+        has_one = admin @ MagicRouletteError::UnauthorizedAdmin
+    )]
+    pub table: Account<'info, Table>,
+    #[account(constraint = table.mint == Some(mint.key()) @ MagicRouletteError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program,
+    )]
+    pub admin_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawFees<'info> {
+    pub fn handler(&mut self, amount: Option<u64>) -> Result<()> {
+        let fee_vault_balance = if self.table.mint.is_none() {
+            self.fee_vault
+                .lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(0))
+        } else {
+            require_token_account(&self.fee_vault_token_account)?.amount
+        };
+        // referral rewards are owed to referrers
+        let withdrawable_amount =
+            fee_vault_balance.saturating_sub(self.table.unclaimed_referral_rewards);
+
+        let amount = if let Some(amount) = amount {
+            require!(
+                amount <= withdrawable_amount,
+                MagicRouletteError::VaultNotWithdrawable
+            );
+
+            amount
+        } else {
+            withdrawable_amount
+        };
+
+        let table_key = self.table.key();
+        let fee_vault_seeds: &[&[u8]] = &[
+            FEE_VAULT_SEED,
+            table_key.as_ref(),
+            &[self.table.fee_vault_bump],
+        ];
+
+        if self.table.mint.is_none() {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.fee_vault.to_account_info(),
+                        to: self.admin.to_account_info(),
+                    },
+                )
+                .with_signer(&[fee_vault_seeds]),
+                amount,
+            )?;
+        } else {
+            transfer_tokens(
+                require_token_account(&self.token_program)?,
+                require_token_account(&self.mint)?,
+                require_token_account(&self.fee_vault_token_account)?.to_account_info(),
+                require_token_account(&self.admin_token_account)?.to_account_info(),
+                self.fee_vault.to_account_info(),
+                amount,
+                &[fee_vault_seeds],
+            )?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        emit!(FeesWithdrawn {
+            table: table_key,
+            amount,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
-use anchor_lang::{
-    prelude::*,
-    system_program::{transfer, Transfer},
-};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{error::MagicRouletteError, events::VaultWithdrawn, Table, TABLE_SEED, VAULT_SEED};
+use crate::{
+    error::MagicRouletteError,
+    events::VaultWithdrawn,
+    utils::{require_token_account, Vault},
+    LpPosition, Round, Table, ROUND_SEED, TABLE_SEED, VAULT_SEED,
+};
 
 #[derive(Accounts)]
 pub struct WithdrawVault<'info> {
@@ -12,23 +15,65 @@ pub struct WithdrawVault<'info> {
     /// CHECK: Vault for holding round bet amounts, system account
     #[account(
         mut,
-        seeds = [VAULT_SEED],
+        seeds = [VAULT_SEED, table.key().as_ref()],
         bump = table.vault_bump
     )]
     pub vault: UncheckedAccount<'info>,
     #[account(
-        seeds = [TABLE_SEED],
+        mut,
+        seeds = [TABLE_SEED, table.table_id.to_le_bytes().as_ref()],
         bump = table.bump,
         has_one = admin @ MagicRouletteError::UnauthorizedAdmin
     )]
     pub table: Account<'info, Table>,
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            table.key().as_ref(),
+            table.current_round_number.to_le_bytes().as_ref()
+        ],
+        bump = current_round.bump
+    )]
+    pub current_round: Box<Account<'info, Round>>,
+    #[account(constraint = table.mint == Some(mint.key()) @ MagicRouletteError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program,
+    )]
+    pub admin_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> WithdrawVault<'info> {
     pub fn handler(&mut self, amount: Option<u64>) -> Result<()> {
-        let min_rent = Rent::get()?.minimum_balance(0);
-        let withdrawable_amount = self.vault.lamports().saturating_sub(min_rent);
+        let vault_balance = self.vault_accounts().balance()?;
+        let bankroll = self.table.bankroll(vault_balance, &self.current_round);
+
+        // amounts owed to players for settled rounds and the worst outcome of the current round can't be withdrawn
+        let mut withdrawable_amount = self.table.free_balance(vault_balance, &self.current_round);
+
+        // once liquidity providers joined, the admin can only withdraw the share of the house
+        if self.table.total_shares > 0 {
+            let house_amount = LpPosition::amount_for_shares(
+                self.table.withdrawable_house_shares(),
+                self.table.total_shares,
+                bankroll,
+            )
+            .ok_or(MagicRouletteError::MathOverflow)?;
+
+            withdrawable_amount = withdrawable_amount.min(house_amount);
+        }
 
         let amount = if let Some(amount) = amount {
             require!(
@@ -41,24 +86,30 @@ impl<'info> WithdrawVault<'info> {
             withdrawable_amount
         };
 
-        let vault_seeds: &[&[u8]] = &[VAULT_SEED, &[self.table.vault_bump]];
+        if self.table.total_shares > 0 && amount > 0 {
+            let shares = LpPosition::shares_to_burn(amount, self.table.total_shares, bankroll)
+                .ok_or(MagicRouletteError::MathOverflow)?;
 
-        transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                Transfer {
-                    from: self.vault.to_account_info(),
-                    to: self.admin.to_account_info(),
-                },
-            )
-            .with_signer(&[vault_seeds]),
-            amount,
-        )?;
+            require!(
+                shares <= self.table.withdrawable_house_shares(),
+                MagicRouletteError::InsufficientShares
+            );
+
+            self.table.total_shares -= shares;
+        }
+
+        let destination = match self.table.mint {
+            Some(_) => require_token_account(&self.admin_token_account)?.to_account_info(),
+            None => self.admin.to_account_info(),
+        };
+
+        self.vault_accounts().transfer_out(destination, amount)?;
 
         let now = Clock::get()?.unix_timestamp;
 
         emit!(
             (VaultWithdrawn {
+                table: self.table.key(),
                 amount,
                 timestamp: now,
             })
@@ -66,4 +117,16 @@ impl<'info> WithdrawVault<'info> {
 
         Ok(())
     }
+
+    /// Funds of the table, held in the vault token account for token-denominated tables.
+    fn vault_accounts(&mut self) -> Vault<'_, 'info> {
+        Vault {
+            table: &self.table,
+            vault: self.vault.to_account_info(),
+            vault_token_account: self.vault_token_account.as_mut(),
+            mint: self.mint.as_ref(),
+            token_program: self.token_program.as_ref(),
+            system_program: &self.system_program,
+        }
+    }
 }
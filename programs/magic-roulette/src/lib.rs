@@ -19,33 +19,52 @@ pub mod magic_roulette {
 
     pub fn initialize_table(
         ctx: Context<InitializeTable>,
-        minimum_bet_amount: u64,
-        round_period_ts: u64,
+        table_id: u64,
+        args: InitializeTableArgs,
     ) -> Result<()> {
-        ctx.accounts
-            .handler(&ctx.bumps, minimum_bet_amount, round_period_ts)
+        ctx.accounts.handler(&ctx.bumps, table_id, args)
     }
 
-    pub fn update_table(
-        ctx: Context<UpdateTable>,
-        minimum_bet_amount: Option<u64>,
-        round_period_ts: Option<u64>,
-        new_admin: Option<Pubkey>,
-    ) -> Result<()> {
-        ctx.accounts
-            .handler(minimum_bet_amount, round_period_ts, new_admin)
+    pub fn update_table(ctx: Context<UpdateTable>, args: UpdateTableArgs) -> Result<()> {
+        ctx.accounts.handler(args)
+    }
+
+    pub fn register_referral(ctx: Context<RegisterReferral>) -> Result<()> {
+        ctx.accounts.handler(&ctx.bumps)
     }
 
     pub fn place_bet(ctx: Context<PlaceBet>, bet_type: BetType, bet_amount: u64) -> Result<()> {
-        ctx.accounts.handler(&ctx.bumps, bet_type, bet_amount)
+        ctx.accounts.handler(
+            &ctx.bumps,
+            vec![Position {
+                bet_type,
+                amount: bet_amount,
+            }],
+        )
+    }
+
+    pub fn place_bets(ctx: Context<PlaceBet>, positions: Vec<Position>) -> Result<()> {
+        ctx.accounts.handler(&ctx.bumps, positions)
     }
 
     pub fn spin_roulette(ctx: Context<SpinRoulette>) -> Result<()> {
         ctx.accounts.handler(&ctx.bumps)
     }
 
-    pub fn advance_round(ctx: Context<AdvanceRound>, randomness: [u8; 32]) -> Result<()> {
-        ctx.accounts.handler(randomness)
+    pub fn retry_spin(ctx: Context<RetrySpin>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn advance_round(
+        ctx: Context<AdvanceRound>,
+        randomness: [u8; 32],
+        caller_seed: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.handler(randomness, caller_seed)
+    }
+
+    pub fn carry_imprisoned_stake(ctx: Context<CarryImprisonedStake>) -> Result<()> {
+        ctx.accounts.handler(&ctx.bumps)
     }
 
     // different handler signature due to remaining accounts
@@ -55,7 +74,40 @@ pub mod magic_roulette {
         ClaimWinnings::handler(ctx)
     }
 
+    // different handler signature due to remaining accounts
+    pub fn settle_bets<'info>(ctx: Context<'_, '_, '_, 'info, SettleBets<'info>>) -> Result<()> {
+        SettleBets::handler(ctx)
+    }
+
+    pub fn close_losing_bet(ctx: Context<CloseLosingBet>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn close_round(ctx: Context<CloseRound>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn sweep_expired(ctx: Context<SweepExpired>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn lp_deposit(ctx: Context<LpDeposit>, amount: u64) -> Result<()> {
+        ctx.accounts.handler(&ctx.bumps, amount)
+    }
+
+    pub fn lp_withdraw(ctx: Context<LpWithdraw>, shares: u64) -> Result<()> {
+        ctx.accounts.handler(shares)
+    }
+
     pub fn withdraw_vault(ctx: Context<WithdrawVault>, amount: Option<u64>) -> Result<()> {
         ctx.accounts.handler(amount)
     }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: Option<u64>) -> Result<()> {
+        ctx.accounts.handler(amount)
+    }
+
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        ctx.accounts.handler()
+    }
 }
@@ -1,6 +1,8 @@
 use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
 use core::mem::size_of;
 
+use crate::{Wheel, ZeroRule};
+
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq)]
 pub enum BetType {
     /// Bet on a single number (0-36, 37 for 00).
@@ -31,10 +33,12 @@ pub enum BetType {
     High,
     /// Bet on low numbers (1-18).
     Low,
+    /// Bet on 0, 1, 2, 3. Only available on European wheels.
+    TopLine,
 }
 
 impl BetType {
-    pub const MAX_OUTCOME: u8 = 37; // 0-36 + 00
+    pub const MAX_OUTCOME: u8 = 37; // 0-36 + 00, highest outcome across all wheels
 
     /// Check if two numbers form a valid split bet (adjacent horizontally or vertically)
     fn is_valid_split(n1: u8, n2: u8) -> bool {
@@ -155,11 +159,11 @@ impl BetType {
         true
     }
 
-    pub fn is_valid(&self) -> bool {
+    pub fn is_valid(&self, wheel: Wheel) -> bool {
         match self {
             BetType::StraightUp { number } => {
-                // Must be 0-36 or 37 (representing 00)
-                *number <= Self::MAX_OUTCOME
+                // Must be 0-36 or 37 (representing 00) on wheels that have it
+                *number <= wheel.max_outcome()
             }
             BetType::Split { numbers } => {
                 // Must have exactly 2 unique numbers that are adjacent
@@ -167,9 +171,10 @@ impl BetType {
                     return false;
                 }
                 if numbers[0] > 36 || numbers[1] > 36 {
-                    // Special case: 0 and 00 (37) can form a split
-                    return (numbers[0] == 0 && numbers[1] == 37)
-                        || (numbers[0] == 37 && numbers[1] == 0);
+                    // Special case: 0 and 00 (37) can form a split, if the wheel has 00
+                    return wheel == Wheel::American
+                        && ((numbers[0] == 0 && numbers[1] == 37)
+                            || (numbers[0] == 37 && numbers[1] == 0));
                 }
                 Self::is_valid_split(numbers[0], numbers[1])
             }
@@ -194,8 +199,8 @@ impl BetType {
                 Self::is_valid_corner(numbers)
             }
             BetType::FiveNumber => {
-                // Always valid - this bet is always 0, 00, 1, 2, 3
-                true
+                // This bet is always 0, 00, 1, 2, 3, so it needs 00 on the wheel
+                wheel == Wheel::American
             }
             BetType::Line { numbers } => {
                 // Must be 6 unique consecutive numbers across two adjacent rows
@@ -224,6 +229,10 @@ impl BetType {
                 // Always valid
                 true
             }
+            BetType::TopLine => {
+                // This bet is always 0, 1, 2, 3, replacing FiveNumber on single zero wheels
+                wheel == Wheel::European
+            }
         }
     }
 
@@ -234,6 +243,7 @@ impl BetType {
             BetType::Street { numbers } => numbers.contains(&outcome),
             BetType::Corner { numbers } => numbers.contains(&outcome),
             BetType::FiveNumber => matches!(outcome, 0 | 1 | 2 | 3 | 37),
+            BetType::TopLine => outcome <= 3,
             BetType::Line { numbers } => numbers.contains(&outcome),
             BetType::Column { column } => {
                 // Column: 1, 2, or 3. 0 and 37 (00) are not in any column
@@ -317,38 +327,287 @@ impl BetType {
         }
     }
 
-    pub fn payout_multiplier(&self) -> u8 {
-        match self {
-            BetType::StraightUp { .. } => 35,
-            BetType::Split { .. } => 17,
-            BetType::Street { .. } => 11,
-            BetType::Corner { .. } => 8,
-            BetType::FiveNumber => 6,
-            BetType::Line { .. } => 5,
-            BetType::Column { .. } => 2,
-            BetType::Dozen { .. } => 2,
+    /// Check if the bet pays 1 to 1 (red, black, even, odd, high, low)
+    pub fn is_even_money(&self) -> bool {
+        matches!(
+            self,
             BetType::Red
-            | BetType::Black
-            | BetType::Even
-            | BetType::Odd
-            | BetType::High
-            | BetType::Low => 1,
+                | BetType::Black
+                | BetType::Even
+                | BetType::Odd
+                | BetType::High
+                | BetType::Low
+        )
+    }
+
+    /// Check if the outcome is 0 or 00
+    pub fn is_zero(outcome: u8) -> bool {
+        outcome == 0 || outcome == 37
+    }
+
+    pub fn kind(&self) -> BetKind {
+        match self {
+            BetType::StraightUp { .. } => BetKind::StraightUp,
+            BetType::Split { .. } => BetKind::Split,
+            BetType::Street { .. } => BetKind::Street,
+            BetType::Corner { .. } => BetKind::Corner,
+            BetType::FiveNumber => BetKind::FiveNumber,
+            BetType::Line { .. } => BetKind::Line,
+            BetType::Column { .. } => BetKind::Column,
+            BetType::Dozen { .. } => BetKind::Dozen,
+            BetType::Red => BetKind::Red,
+            BetType::Black => BetKind::Black,
+            BetType::Even => BetKind::Even,
+            BetType::Odd => BetKind::Odd,
+            BetType::High => BetKind::High,
+            BetType::Low => BetKind::Low,
+            BetType::TopLine => BetKind::TopLine,
+        }
+    }
+}
+
+/// Kind of a bet regardless of the numbers it covers, used to index per-table settings.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum BetKind {
+    StraightUp,
+    Split,
+    Street,
+    Corner,
+    FiveNumber,
+    Line,
+    Column,
+    Dozen,
+    Red,
+    Black,
+    Even,
+    Odd,
+    High,
+    Low,
+    TopLine,
+}
+
+impl BetKind {
+    pub const COUNT: usize = 15;
+
+    pub const ALL: [BetKind; BetKind::COUNT] = [
+        BetKind::StraightUp,
+        BetKind::Split,
+        BetKind::Street,
+        BetKind::Corner,
+        BetKind::FiveNumber,
+        BetKind::Line,
+        BetKind::Column,
+        BetKind::Dozen,
+        BetKind::Red,
+        BetKind::Black,
+        BetKind::Even,
+        BetKind::Odd,
+        BetKind::High,
+        BetKind::Low,
+        BetKind::TopLine,
+    ];
+
+    /// Standard roulette payouts, indexed by `BetKind`.
+    pub const STANDARD_PAYOUT_MULTIPLIERS: [u8; BetKind::COUNT] =
+        [35, 17, 11, 8, 6, 5, 2, 2, 1, 1, 1, 1, 1, 1, 8];
+
+    /// Amount of numbers on the wheel covered by a bet of this kind.
+    pub fn coverage(&self) -> u8 {
+        match self {
+            BetKind::StraightUp => 1,
+            BetKind::Split => 2,
+            BetKind::Street => 3,
+            BetKind::Corner | BetKind::TopLine => 4,
+            BetKind::FiveNumber => 5,
+            BetKind::Line => 6,
+            BetKind::Column | BetKind::Dozen => 12,
+            BetKind::Red
+            | BetKind::Black
+            | BetKind::Even
+            | BetKind::Odd
+            | BetKind::High
+            | BetKind::Low => 18,
+        }
+    }
+
+    /// Highest multiplier that does not exceed true odds on `wheel`, i.e. never gives the player an edge.
+    pub fn max_payout_multiplier(&self, wheel: Wheel) -> u8 {
+        wheel.outcome_count() / self.coverage() - 1
+    }
+
+    /// Check that every multiplier pays out something and none exceeds true odds
+    pub fn is_valid_payout_schedule(
+        payout_multipliers: &[u8; BetKind::COUNT],
+        wheel: Wheel,
+    ) -> bool {
+        BetKind::ALL.iter().all(|kind| {
+            let multiplier = payout_multipliers[*kind as usize];
+            multiplier > 0 && multiplier <= kind.max_payout_multiplier(wheel)
+        })
+    }
+}
+
+/// A single wager placed on the layout.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub bet_type: BetType,
+    /// Amount of lamports wagered on this position.
+    pub amount: u64,
+}
+
+impl Position {
+    /// Original bet amount plus winnings, paid out when the position wins.
+    pub fn payout(&self, payout_multipliers: &[u8; BetKind::COUNT]) -> Option<u64> {
+        let multiplier = payout_multipliers[self.bet_type.kind() as usize];
+
+        self.amount
+            .checked_mul(multiplier as u64)?
+            .checked_add(self.amount)
+    }
+
+    /// Amount owed to the player for this position on `outcome`.
+    ///
+    /// Besides winning positions, this covers half of the stake returned on zero under La Partage.
+    pub fn settlement(
+        &self,
+        outcome: u8,
+        payout_multipliers: &[u8; BetKind::COUNT],
+        zero_rule: ZeroRule,
+    ) -> Option<u64> {
+        if self.bet_type.is_winner(outcome) {
+            return self.payout(payout_multipliers);
+        }
+
+        if zero_rule == ZeroRule::LaPartage
+            && self.bet_type.is_even_money()
+            && BetType::is_zero(outcome)
+        {
+            return Some(self.amount / 2);
+        }
+
+        Some(0)
+    }
+
+    /// Most the house may owe for this position on `outcome`.
+    ///
+    /// Same as the settlement, except that a stake held en prison is assumed to be returned.
+    pub fn liability(
+        &self,
+        outcome: u8,
+        payout_multipliers: &[u8; BetKind::COUNT],
+        zero_rule: ZeroRule,
+    ) -> Option<u64> {
+        if zero_rule == ZeroRule::EnPrison
+            && self.bet_type.is_even_money()
+            && BetType::is_zero(outcome)
+        {
+            return Some(self.amount);
+        }
+
+        self.settlement(outcome, payout_multipliers, zero_rule)
+    }
+
+    /// Amount owed to the player for this position on `outcome` once its stake is held en prison.
+    ///
+    /// Only the stake is returned when it wins, and it is lost on anything else, zero included.
+    pub fn imprisoned_settlement(&self, outcome: u8) -> u64 {
+        if self.bet_type.is_winner(outcome) {
+            self.amount
+        } else {
+            0
         }
     }
 }
 
+/// Ticket holding every position a player has placed in a round.
 #[account]
 pub struct Bet {
     /// Player who placed the bet.
     pub player: Pubkey,
     /// Round in which the bet was placed.
     pub round: Pubkey,
-    /// Amount of lamports bet.
+    /// Total amount of lamports wagered across all positions.
     pub amount: u64,
     pub bump: u8,
     /// Boolean that indicates if the prize for a winning bet has been claimed.
     pub is_claimed: bool,
-    pub bet_type: BetType,
+    /// Boolean that indicates if stakes held en prison have been carried into a later round.
+    pub is_carried: bool,
+    /// Positions placed in the round, up to `Bet::MAX_POSITIONS`.
+    pub positions: Vec<Position>,
+    /// Even-money stakes held en prison in earlier rounds and carried into this one, one per bet type.
+    pub imprisoned_positions: Vec<Position>,
+}
+
+impl Bet {
+    pub const MAX_POSITIONS: usize = 16;
+    /// One imprisoned position for each even-money bet type.
+    pub const MAX_IMPRISONED_POSITIONS: usize = 6;
+
+    /// Sum of settlements for every position on `outcome`, imprisoned stakes included.
+    pub fn winnings(
+        &self,
+        outcome: u8,
+        payout_multipliers: &[u8; BetKind::COUNT],
+        zero_rule: ZeroRule,
+    ) -> Option<u64> {
+        let winnings = self
+            .positions
+            .iter()
+            .try_fold(0_u64, |winnings, position| {
+                winnings.checked_add(position.settlement(outcome, payout_multipliers, zero_rule)?)
+            })?;
+
+        self.imprisoned_positions
+            .iter()
+            .try_fold(winnings, |winnings, position| {
+                winnings.checked_add(position.imprisoned_settlement(outcome))
+            })
+    }
+
+    /// Amount of positions that win on `outcome`.
+    pub fn winning_positions(&self, outcome: u8) -> u64 {
+        self.positions
+            .iter()
+            .filter(|position| position.bet_type.is_winner(outcome))
+            .count() as u64
+    }
+
+    /// Check if even-money stakes are held en prison by a zero `outcome` and not yet carried
+    pub fn is_awaiting_carry(&self, outcome: u8, zero_rule: ZeroRule) -> bool {
+        !self.is_carried
+            && zero_rule == ZeroRule::EnPrison
+            && BetType::is_zero(outcome)
+            && self
+                .positions
+                .iter()
+                .any(|position| position.bet_type.is_even_money())
+    }
+
+    /// Stakes of even-money positions, which are held en prison when landing on zero.
+    pub fn imprisoned_stake(&self) -> Option<u64> {
+        self.positions
+            .iter()
+            .filter(|position| position.bet_type.is_even_money())
+            .try_fold(0_u64, |stake, position| stake.checked_add(position.amount))
+    }
+
+    /// Adds a stake held en prison in an earlier round, merged with the imprisoned position of the same bet type.
+    pub fn carry(&mut self, position: &Position) -> Option<()> {
+        match self
+            .imprisoned_positions
+            .iter_mut()
+            .find(|imprisoned| imprisoned.bet_type == position.bet_type)
+        {
+            Some(imprisoned) => {
+                imprisoned.amount = imprisoned.amount.checked_add(position.amount)?
+            }
+            None => self.imprisoned_positions.push(*position),
+        }
+
+        Some(())
+    }
 }
 
 impl Space for Bet {
@@ -359,5 +618,100 @@ impl Space for Bet {
         + size_of::<u64>()
         + size_of::<u8>()
         + size_of::<bool>()
-        + BetType::INIT_SPACE;
+        + size_of::<bool>()
+        + size_of::<u32>()
+        + Bet::MAX_POSITIONS * Position::INIT_SPACE
+        + size_of::<u32>()
+        + Bet::MAX_IMPRISONED_POSITIONS * Position::INIT_SPACE;
+}
+
+#[cfg(test)]
+mod tests {
+    use ephemeral_vrf_sdk::rnd::random_u8_with_range;
+
+    use super::*;
+
+    #[test]
+    fn bet_types_follow_the_wheel() {
+        let double_zero_bets = [
+            BetType::FiveNumber,
+            BetType::StraightUp { number: 37 },
+            BetType::Split { numbers: [0, 37] },
+            BetType::Split { numbers: [37, 0] },
+        ];
+
+        for bet_type in double_zero_bets {
+            assert!(bet_type.is_valid(Wheel::American));
+            assert!(!bet_type.is_valid(Wheel::European));
+        }
+
+        assert!(BetType::TopLine.is_valid(Wheel::European));
+        assert!(!BetType::TopLine.is_valid(Wheel::American));
+
+        let shared_bets = [
+            BetType::StraightUp { number: 0 },
+            BetType::StraightUp { number: 36 },
+            BetType::Split { numbers: [1, 4] },
+            BetType::Corner {
+                numbers: [1, 2, 4, 5],
+            },
+            BetType::Red,
+        ];
+
+        for bet_type in shared_bets {
+            assert!(bet_type.is_valid(Wheel::American));
+            assert!(bet_type.is_valid(Wheel::European));
+        }
+
+        assert!(!BetType::StraightUp { number: 38 }.is_valid(Wheel::American));
+    }
+
+    #[test]
+    fn european_spins_never_land_on_double_zero() {
+        for byte in 0..=u8::MAX {
+            let mut randomness = [u8::MAX; 32];
+            randomness[31] = byte;
+
+            assert!(random_u8_with_range(&randomness, 0, Wheel::European.max_outcome()) <= 36);
+            assert!(random_u8_with_range(&[byte; 32], 0, Wheel::European.max_outcome()) <= 36);
+        }
+    }
+
+    #[test]
+    fn payout_schedule_is_bounded_by_true_odds() {
+        let standard = BetKind::STANDARD_PAYOUT_MULTIPLIERS;
+
+        assert!(BetKind::is_valid_payout_schedule(
+            &standard,
+            Wheel::American
+        ));
+        assert!(BetKind::is_valid_payout_schedule(
+            &standard,
+            Wheel::European
+        ));
+
+        // every bet has to pay out something
+        let mut unpaid = standard;
+        unpaid[BetKind::Red as usize] = 0;
+        assert!(!BetKind::is_valid_payout_schedule(&unpaid, Wheel::American));
+
+        // 37 to 1 on a straight up is true odds with 00, but gives the player an edge without it
+        let mut generous = standard;
+        generous[BetKind::StraightUp as usize] = 37;
+        assert!(BetKind::is_valid_payout_schedule(
+            &generous,
+            Wheel::American
+        ));
+        assert!(!BetKind::is_valid_payout_schedule(
+            &generous,
+            Wheel::European
+        ));
+
+        let mut even_money = standard;
+        even_money[BetKind::Even as usize] = 2;
+        assert!(!BetKind::is_valid_payout_schedule(
+            &even_money,
+            Wheel::European
+        ));
+    }
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Outcomes of the latest spins of a table, kept in a ring buffer so frontends can read them with one fetch.
+#[account(zero_copy)]
+pub struct History {
+    /// Table the spins belong to.
+    pub table: Pubkey,
+    /// Amount of spins recorded since the table was initialized.
+    ///
+    /// The next spin is written at `count % History::CAPACITY`.
+    pub count: u64,
+    pub entries: [HistoryEntry; History::CAPACITY],
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+#[zero_copy]
+pub struct HistoryEntry {
+    pub round_number: u64,
+    /// Timestamp when the round was settled.
+    pub timestamp: i64,
+    /// The number that won (0-36, with 37 representing 00)
+    pub outcome: u8,
+    pub _padding: [u8; 7],
+}
+
+impl History {
+    /// Amount of spins kept, older spins are overwritten.
+    pub const CAPACITY: usize = 256;
+
+    /// Records a spin, overwriting the oldest one once the buffer is full.
+    pub fn push(&mut self, round_number: u64, outcome: u8, timestamp: i64) {
+        let index = (self.count % History::CAPACITY as u64) as usize;
+
+        self.entries[index] = HistoryEntry {
+            round_number,
+            timestamp,
+            outcome,
+            _padding: [0; 7],
+        };
+        self.count += 1;
+    }
+}
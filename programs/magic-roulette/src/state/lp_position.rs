@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+/// Share of the bankroll of a table held by a liquidity provider.
+#[account]
+#[derive(InitSpace)]
+pub struct LpPosition {
+    /// Liquidity provider owning the shares.
+    pub provider: Pubkey,
+    /// Amount of bankroll shares held.
+    pub shares: u64,
+    /// Timestamp of the latest deposit, withdrawals open once the table cooldown has passed.
+    pub last_deposit_ts: i64,
+    pub bump: u8,
+}
+
+impl LpPosition {
+    /// Shares minted for depositing `amount` into `bankroll`, rounded down.
+    ///
+    /// Shares are worth one lamport each while none exist.
+    pub fn shares_for_amount(amount: u64, total_shares: u64, bankroll: u64) -> Option<u64> {
+        if total_shares == 0 {
+            return Some(amount);
+        }
+
+        let shares = (amount as u128)
+            .checked_mul(total_shares as u128)?
+            .checked_div(bankroll as u128)?;
+
+        u64::try_from(shares).ok()
+    }
+
+    /// Shares burned for withdrawing `amount` from `bankroll`, rounded up so the pool never loses out.
+    pub fn shares_to_burn(amount: u64, total_shares: u64, bankroll: u64) -> Option<u64> {
+        let shares = (amount as u128)
+            .checked_mul(total_shares as u128)?
+            .checked_add((bankroll as u128).checked_sub(1)?)?
+            .checked_div(bankroll as u128)?;
+
+        u64::try_from(shares).ok()
+    }
+
+    /// Lamports of `bankroll` that `shares` are worth, rounded down.
+    pub fn amount_for_shares(shares: u64, total_shares: u64, bankroll: u64) -> Option<u64> {
+        let amount = (shares as u128)
+            .checked_mul(bankroll as u128)?
+            .checked_div(total_shares as u128)?;
+
+        u64::try_from(amount).ok()
+    }
+}
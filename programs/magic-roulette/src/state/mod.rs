@@ -1,7 +1,15 @@
 pub mod bet;
+pub mod history;
+pub mod lp_position;
+pub mod player_stats;
+pub mod referral;
 pub mod round;
 pub mod table;
 
 pub use bet::*;
+pub use history::*;
+pub use lp_position::*;
+pub use player_stats::*;
+pub use referral::*;
 pub use round::*;
 pub use table::*;
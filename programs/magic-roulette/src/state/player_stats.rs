@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Lifetime statistics of a player across all tables of the same denomination.
+///
+/// Amounts of different mints can't be added up, so each mint has its own stats.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerStats {
+    /// Player the statistics belong to.
+    pub player: Pubkey,
+    /// Mint the amounts are denominated in, the default pubkey for lamports.
+    pub mint: Pubkey,
+    /// Total amount wagered across all positions.
+    pub total_wagered: u64,
+    /// Total winnings paid out, including returned stakes.
+    pub total_won: u64,
+    /// Amount of positions placed.
+    pub bets_placed: u64,
+    /// Amount of positions that won.
+    pub wins: u64,
+    /// Largest winnings paid out for a single bet.
+    pub biggest_win: u64,
+    pub bump: u8,
+}
+
+impl PlayerStats {
+    /// Records `positions` placed for a total of `amount`.
+    pub fn record_bet(&mut self, amount: u64, positions: u64) -> Option<()> {
+        self.total_wagered = self.total_wagered.checked_add(amount)?;
+        self.bets_placed = self.bets_placed.checked_add(positions)?;
+
+        Some(())
+    }
+
+    /// Records `winnings` paid out for a bet with `winning_positions` that won.
+    pub fn record_win(&mut self, winnings: u64, winning_positions: u64) -> Option<()> {
+        self.total_won = self.total_won.checked_add(winnings)?;
+        self.wins = self.wins.checked_add(winning_positions)?;
+        self.biggest_win = self.biggest_win.max(winnings);
+
+        Some(())
+    }
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Rewards earned by a referrer on the wagers of the players they referred to a table.
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    /// Account that referred the players and claims the rewards.
+    pub referrer: Pubkey,
+    /// Rewards credited and not yet claimed.
+    pub accrued_rewards: u64,
+    /// Rewards credited since the referral was registered.
+    pub total_rewards: u64,
+    pub bump: u8,
+}
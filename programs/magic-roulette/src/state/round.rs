@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{BetKind, BetType, Position, Wheel, ZeroRule};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Round {
@@ -7,23 +9,126 @@ pub struct Round {
     ///
     /// Starts at 1 and increments by 1 for each new round.
     pub round_number: u64,
+    /// Account that paid the rent of the round, refunded when the round is closed.
+    pub payer: Pubkey,
     /// Lamports pooled from all bets in this round.
     pub pool_amount: u64,
     /// Boolean indicating if the round has been spun and is awaiting VRF callback.
     pub is_spun: bool,
+    /// Timestamp of the latest randomness request for this round.
+    pub spun_ts: i64,
+    /// Timestamp when the outcome of the round was set.
+    pub settled_ts: i64,
+    /// Amount of bets of this round that have not been closed yet.
+    pub bet_count: u32,
     pub bump: u8,
     /// The number that won (0-36, with 37 representing 00)
     pub outcome: Option<u8>,
+    /// Payout multipliers of the table when the round was opened, every bet of the round is paid with them.
+    pub payout_multipliers: [u8; BetKind::COUNT],
+    /// Zero rule of the table when the round was opened, every bet of the round is settled with it.
+    pub zero_rule: ZeroRule,
+    /// Lamports owed to players for each possible outcome, indexed by outcome.
+    pub exposure: [u64; Round::OUTCOME_COUNT],
+    /// Lamports staked on positions that win for each possible outcome, indexed by outcome.
+    pub winning_stakes: [u64; Round::OUTCOME_COUNT],
+    /// Lamports staked on positions that won the outcome, set when the round is settled.
+    pub total_winning_stake: u64,
+    /// Lamports owed to players for the outcome, set when the round is settled.
+    ///
+    /// Stakes held en prison are counted as returned.
+    pub total_payout: u64,
+    /// Seed of the latest randomness request for this round.
+    pub caller_seed: [u8; 32],
+    /// Randomness the outcome was derived from, set when the round is settled.
+    pub randomness: [u8; 32],
 }
 
 impl Round {
-    pub fn new(round_number: u64, bump: u8) -> Self {
+    /// Amount of outcomes tracked, enough for any wheel.
+    pub const OUTCOME_COUNT: usize = BetType::MAX_OUTCOME as usize + 1;
+
+    pub fn new(
+        round_number: u64,
+        payer: Pubkey,
+        bump: u8,
+        payout_multipliers: [u8; BetKind::COUNT],
+        zero_rule: ZeroRule,
+    ) -> Self {
         Self {
             round_number,
+            payer,
             pool_amount: 0,
             is_spun: false,
+            spun_ts: 0,
+            settled_ts: 0,
+            bet_count: 0,
             bump,
             outcome: None,
+            payout_multipliers,
+            zero_rule,
+            exposure: [0; Round::OUTCOME_COUNT],
+            winning_stakes: [0; Round::OUTCOME_COUNT],
+            total_winning_stake: 0,
+            total_payout: 0,
+            caller_seed: [0; 32],
+            randomness: [0; 32],
+        }
+    }
+
+    /// Adds the liability and winning stake of `position` to every outcome of `wheel`.
+    pub fn add_exposure(&mut self, position: &Position, wheel: Wheel) -> Option<()> {
+        for outcome in 0..=wheel.max_outcome() {
+            let liability =
+                position.liability(outcome, &self.payout_multipliers, self.zero_rule)?;
+            let exposure = &mut self.exposure[outcome as usize];
+
+            *exposure = exposure.checked_add(liability)?;
+
+            if position.bet_type.is_winner(outcome) {
+                let winning_stake = &mut self.winning_stakes[outcome as usize];
+
+                *winning_stake = winning_stake.checked_add(position.amount)?;
+            }
+        }
+
+        Some(())
+    }
+
+    /// Adds the liability of a stake carried en prison into this round to every outcome of `wheel`.
+    pub fn add_imprisoned_exposure(&mut self, position: &Position, wheel: Wheel) -> Option<()> {
+        for outcome in 0..=wheel.max_outcome() {
+            let exposure = &mut self.exposure[outcome as usize];
+
+            *exposure = exposure.checked_add(position.imprisoned_settlement(outcome))?;
         }
+
+        Some(())
+    }
+
+    /// Check if the claim window of a settled round has passed.
+    pub fn is_claim_expired(&self, now: i64, claim_window_secs: u64) -> bool {
+        self.outcome.is_some() && now >= self.settled_ts.saturating_add(claim_window_secs as i64)
+    }
+
+    /// Sets the outcome of the round, along with the randomness it was derived from, and records its totals.
+    pub fn settle(&mut self, outcome: u8, randomness: [u8; 32], now: i64) {
+        self.outcome = Some(outcome);
+        self.randomness = randomness;
+        self.settled_ts = now;
+        self.total_winning_stake = self.winning_stakes[outcome as usize];
+        self.total_payout = self.exposure[outcome as usize];
+    }
+
+    /// Lamports won by the house on the settled round, negative when the house lost.
+    pub fn house_pnl(&self) -> Option<i64> {
+        let pnl = i128::from(self.pool_amount) - i128::from(self.total_payout);
+
+        i64::try_from(pnl).ok()
+    }
+
+    /// Worst-case amount owed to players across all outcomes.
+    pub fn max_exposure(&self) -> u64 {
+        self.exposure.iter().copied().max().unwrap_or_default()
     }
 }
@@ -1,18 +1,158 @@
 use anchor_lang::prelude::*;
 
+use crate::{BetKind, Round, MAX_BASIS_POINTS, MAX_CRANK_FEE_BPS, MIN_HOUSE_SHARES};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Table {
+    /// Identifier chosen at initialization, mixed into every PDA seed of this table.
+    pub table_id: u64,
     /// Authority of the table.
     pub admin: Pubkey,
     /// Minimum bet amount in lamports.
     pub minimum_bet_amount: u64,
+    /// Maximum bet amount in lamports for each bet kind, indexed by `BetKind`.
+    pub maximum_bet_amounts: [u64; BetKind::COUNT],
+    /// Maximum lamports a single round may owe to players on any outcome.
+    pub maximum_round_liability: u64,
     /// Number of the current round.
     pub current_round_number: u64,
     /// Timestamp when round can be advanced.
     pub next_round_ts: i64,
     /// Timestamp for how long each round lasts.
     pub round_period_ts: u64,
+    /// Seconds to wait for the VRF callback before the spin can be retried.
+    pub spin_timeout_ts: u64,
+    /// Seconds after a round is settled during which its bets can be claimed.
+    pub claim_window_secs: u64,
+    /// Lamports paid from the vault to the cranker for each winning bet paid out by `settle_bets`.
+    pub crank_fee: u64,
+    /// Lamports of winnings and imprisoned stakes forfeited by bets left unclaimed past the claim window.
+    pub forfeited_winnings: u64,
+    /// Lamports owed to players by settled rounds that have not been claimed yet.
+    pub unclaimed_liability: u64,
+    /// Shares of the bankroll, held by liquidity providers and the house.
+    pub total_shares: u64,
+    /// Shares of the bankroll held by liquidity providers, the rest belong to the house.
+    pub lp_shares: u64,
+    /// Seconds after a deposit before a liquidity provider can withdraw.
+    pub lp_cooldown_secs: u64,
+    /// Rake in basis points charged on top of every bet, collected in the fee vault.
+    pub rake_bps: u16,
+    /// Share of referred wagers in basis points credited to the referrer, paid out of the rake.
+    pub referral_bps: u16,
+    /// Referral rewards credited but not yet claimed, reserved in the fee vault.
+    pub unclaimed_referral_rewards: u64,
+    /// VRF oracle queue that spins of this table must request randomness from.
+    pub oracle_queue: Pubkey,
+    /// Mint that bets and payouts are denominated in, lamports are used when `None`.
+    pub mint: Option<Pubkey>,
+    /// Winnings multiplier paid out for each bet kind, indexed by `BetKind`.
+    pub payout_multipliers: [u8; BetKind::COUNT],
+    /// Layout of the wheel, which decides the possible outcomes and bets.
+    pub wheel: Wheel,
+    /// What happens to even-money bets when the ball lands on zero.
+    pub zero_rule: ZeroRule,
     pub bump: u8,
     pub vault_bump: u8,
+    pub fee_vault_bump: u8,
+}
+
+impl Table {
+    /// Check that every maximum bet amount allows at least the minimum bet amount
+    pub fn is_valid_bet_range(
+        minimum_bet_amount: u64,
+        maximum_bet_amounts: &[u64; BetKind::COUNT],
+    ) -> bool {
+        maximum_bet_amounts
+            .iter()
+            .all(|maximum_bet_amount| *maximum_bet_amount >= minimum_bet_amount)
+    }
+
+    /// Rake charged for wagering `amount`, rounded down.
+    pub fn rake(&self, amount: u64) -> Option<u64> {
+        basis_points_of(amount, self.rake_bps)
+    }
+
+    /// Reward credited to the referrer of a player wagering `amount`, rounded down.
+    pub fn referral_reward(&self, amount: u64) -> Option<u64> {
+        basis_points_of(amount, self.referral_bps)
+    }
+
+    /// Highest crank fee the table can pay for each bet, a fraction of the minimum bet amount.
+    pub fn max_crank_fee(&self) -> Option<u64> {
+        basis_points_of(self.minimum_bet_amount, MAX_CRANK_FEE_BPS)
+    }
+
+    /// Part of `vault_balance` that is not owed to winners of settled rounds nor needed for the worst
+    /// outcome of `current_round`.
+    pub fn free_balance(&self, vault_balance: u64, current_round: &Round) -> u64 {
+        vault_balance
+            .saturating_sub(self.unclaimed_liability)
+            .saturating_sub(current_round.max_exposure())
+    }
+
+    /// Part of `vault_balance` backing the shares, so house profit or loss only reaches it once a round is settled.
+    pub fn bankroll(&self, vault_balance: u64, current_round: &Round) -> u64 {
+        vault_balance
+            .saturating_sub(self.unclaimed_liability)
+            .saturating_sub(current_round.pool_amount)
+    }
+
+    /// Shares of the bankroll held by the house.
+    pub fn house_shares(&self) -> u64 {
+        self.total_shares.saturating_sub(self.lp_shares)
+    }
+
+    /// Shares the house can burn, `MIN_HOUSE_SHARES` are kept while liquidity providers are in the pool.
+    pub fn withdrawable_house_shares(&self) -> u64 {
+        if self.lp_shares == 0 {
+            return self.house_shares();
+        }
+
+        self.house_shares().saturating_sub(MIN_HOUSE_SHARES)
+    }
+}
+
+/// `bps` basis points of `amount`, rounded down.
+fn basis_points_of(amount: u64, bps: u16) -> Option<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)?
+        .checked_div(MAX_BASIS_POINTS as u128)?;
+
+    u64::try_from(share).ok()
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq)]
+pub enum Wheel {
+    /// Double zero wheel, 0-36 and 00 (37).
+    American,
+    /// Single zero wheel, 0-36.
+    European,
+}
+
+impl Wheel {
+    /// The highest number that can be spun.
+    pub fn max_outcome(&self) -> u8 {
+        match self {
+            Wheel::American => 37,
+            Wheel::European => 36,
+        }
+    }
+
+    /// Amount of pockets on the wheel.
+    pub fn outcome_count(&self) -> u8 {
+        self.max_outcome() + 1
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq)]
+pub enum ZeroRule {
+    /// Even-money bets lose on zero.
+    Standard,
+    /// Half of the stake of even-money bets is returned on zero.
+    LaPartage,
+    /// Stake of even-money bets is carried into the bet of the player in the next open round when landing on zero,
+    /// and is returned if the bet wins that spin.
+    EnPrison,
 }
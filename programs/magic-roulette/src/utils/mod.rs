@@ -1,3 +1,9 @@
 pub mod system;
+pub mod token;
+pub mod vault;
+pub mod vrf;
 
 pub use system::*;
+pub use token::*;
+pub use vault::*;
+pub use vrf::*;
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    },
+    token_interface::{transfer_checked, Mint, TokenInterface, TransferChecked},
+};
+
+use crate::error::MagicRouletteError;
+
+/// Token-2022 mint extensions a table accepts, none of them can move, freeze or withhold vault funds.
+const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 6] = [
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::GroupPointer,
+    ExtensionType::TokenGroup,
+    ExtensionType::GroupMemberPointer,
+    ExtensionType::TokenGroupMember,
+];
+
+/// Transfers `amount` of `mint` between token accounts, works for both SPL Token and Token-2022.
+///
+/// `signer_seeds` is empty unless `authority` is the vault.
+pub fn transfer_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from,
+                mint: mint.to_account_info(),
+                to,
+                authority,
+            },
+        )
+        .with_signer(signer_seeds),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Unwraps the optional token accounts of a token-denominated table.
+pub fn require_token_account<T>(account: &Option<T>) -> Result<&T> {
+    account
+        .as_ref()
+        .ok_or(MagicRouletteError::MissingTokenAccount.into())
+}
+
+/// Check if every extension of `mint` is supported, SPL Token mints have none.
+pub fn has_supported_extensions(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+
+    Ok(mint
+        .get_extension_types()?
+        .iter()
+        .all(|extension| SUPPORTED_MINT_EXTENSIONS.contains(extension)))
+}
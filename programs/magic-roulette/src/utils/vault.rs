@@ -0,0 +1,81 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{error::MagicRouletteError, utils::transfer_tokens, Table, VAULT_SEED};
+
+/// Accounts holding the funds of a table, lamports in the vault itself or tokens in its token account.
+///
+/// Token accounts are only needed for token-denominated tables.
+pub struct Vault<'a, 'info> {
+    pub table: &'a Account<'info, Table>,
+    pub vault: AccountInfo<'info>,
+    pub vault_token_account: Option<&'a mut InterfaceAccount<'info, TokenAccount>>,
+    pub mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<&'a Interface<'info, TokenInterface>>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+impl<'info> Vault<'_, 'info> {
+    /// Balance available to pay out, excluding the rent of lamport vaults.
+    pub fn balance(&mut self) -> Result<u64> {
+        if self.table.mint.is_none() {
+            return Ok(self
+                .vault
+                .lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(0)));
+        }
+
+        let vault_token_account = self
+            .vault_token_account
+            .as_mut()
+            .ok_or(MagicRouletteError::MissingTokenAccount)?;
+
+        // transfers made earlier in the instruction are only reflected once reloaded
+        vault_token_account.reload()?;
+
+        Ok(vault_token_account.amount)
+    }
+
+    /// Pays `amount` to `to`, which is a token account for token-denominated tables and a wallet otherwise.
+    pub fn transfer_out(&mut self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        // although chances are small, it is possible for vault to not have enough funds to pay out winnings
+        require!(
+            self.balance()? >= amount,
+            MagicRouletteError::InsufficientVaultFunds
+        );
+
+        let table_key = self.table.key();
+        let vault_seeds: &[&[u8]] = &[VAULT_SEED, table_key.as_ref(), &[self.table.vault_bump]];
+
+        if self.table.mint.is_none() {
+            return transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.vault.clone(),
+                        to,
+                    },
+                )
+                .with_signer(&[vault_seeds]),
+                amount,
+            );
+        }
+
+        transfer_tokens(
+            self.token_program
+                .ok_or(MagicRouletteError::MissingTokenAccount)?,
+            self.mint.ok_or(MagicRouletteError::MissingTokenAccount)?,
+            self.vault_token_account
+                .as_ref()
+                .ok_or(MagicRouletteError::MissingTokenAccount)?
+                .to_account_info(),
+            to,
+            self.vault.clone(),
+            amount,
+            &[vault_seeds],
+        )
+    }
+}
@@ -0,0 +1,135 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::hashv, instruction::Instruction},
+    system_program,
+};
+use ephemeral_vrf_sdk::{
+    instructions::{create_request_randomness_ix, RequestRandomnessParams},
+    rnd::random_u8_with_range,
+    types::SerializableAccountMeta,
+};
+
+use crate::{instruction, Round, Wheel, ID};
+
+/// Derives the outcome of a spin on `wheel` from the randomness returned by the oracle.
+///
+/// Used by `advance_round`, and kept free of any account so past spins can be verified off-chain
+/// from the `randomness` stored on each `Round`.
+pub fn spin_outcome(randomness: &[u8; 32], wheel: Wheel) -> u8 {
+    random_u8_with_range(randomness, 0, wheel.max_outcome())
+}
+
+/// Derives the seed of a randomness request for `round` of `table`.
+///
+/// Mixes in the most recent slot hash and the state of the pool, so seeds can't be known ahead of the
+/// request and are never shared between requests, retries included.
+pub fn spin_caller_seed(
+    table: &Pubkey,
+    round: &Round,
+    slot_hashes: &AccountInfo,
+    now: i64,
+) -> Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    // entries follow the u64 length prefix, most recent (slot, hash) first
+    let latest_slot_hash = data.get(8..48).ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok(hashv(&[
+        round.round_number.to_le_bytes().as_ref(),
+        table.as_ref(),
+        latest_slot_hash,
+        round.pool_amount.to_le_bytes().as_ref(),
+        round.bet_count.to_le_bytes().as_ref(),
+        now.to_le_bytes().as_ref(),
+    ])
+    .to_bytes())
+}
+
+/// Builds the randomness request for a spin, with `advance_round` as the callback.
+///
+/// Shared by `spin_roulette` and `retry_spin` so both requests settle through the same accounts.
+/// The caller seed is passed back to the callback, identifying the request the randomness answers.
+pub fn create_spin_randomness_ix(
+    payer: Pubkey,
+    oracle_queue: Pubkey,
+    table: Pubkey,
+    current_round: Pubkey,
+    new_round: Pubkey,
+    history: Pubkey,
+    caller_seed: [u8; 32],
+) -> Instruction {
+    create_request_randomness_ix(RequestRandomnessParams {
+        payer,
+        oracle_queue,
+        callback_program_id: ID,
+        callback_discriminator: instruction::AdvanceRound::DISCRIMINATOR.to_vec(),
+        caller_seed,
+        accounts_metas: Some(vec![
+            SerializableAccountMeta {
+                pubkey: table,
+                is_signer: false,
+                is_writable: true,
+            },
+            SerializableAccountMeta {
+                pubkey: current_round,
+                is_signer: false,
+                is_writable: true,
+            },
+            SerializableAccountMeta {
+                pubkey: new_round,
+                is_signer: false,
+                is_writable: true,
+            },
+            SerializableAccountMeta {
+                pubkey: history,
+                is_signer: false,
+                is_writable: true,
+            },
+            SerializableAccountMeta {
+                pubkey: system_program::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]),
+        callback_args: Some(caller_seed.to_vec()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spin_outcome_matches_known_randomness() {
+        let ascending: [u8; 32] = std::array::from_fn(|i| i as u8);
+        assert_eq!(spin_outcome(&ascending, Wheel::American), 31);
+        assert_eq!(spin_outcome(&ascending, Wheel::European), 31);
+
+        // last byte is past the unbiased threshold of both wheels, so the one before it is used
+        let mut skipped = [7; 32];
+        skipped[31] = 250;
+        assert_eq!(spin_outcome(&skipped, Wheel::American), 7);
+        assert_eq!(spin_outcome(&skipped, Wheel::European), 7);
+
+        // 00 on an american wheel wraps around to 0 on a european one
+        let mut double_zero = [0; 32];
+        double_zero[31] = 37;
+        assert_eq!(spin_outcome(&double_zero, Wheel::American), 37);
+        assert_eq!(spin_outcome(&double_zero, Wheel::European), 0);
+
+        // no byte is below the threshold, so the last byte is used with a slight bias
+        assert_eq!(spin_outcome(&[255; 32], Wheel::American), 27);
+        assert_eq!(spin_outcome(&[255; 32], Wheel::European), 33);
+    }
+
+    #[test]
+    fn spin_outcome_stays_on_the_wheel() {
+        for byte in 0..=u8::MAX {
+            let mut randomness = [u8::MAX; 32];
+            randomness[31] = byte;
+
+            assert!(spin_outcome(&randomness, Wheel::American) <= 37);
+            assert!(spin_outcome(&randomness, Wheel::European) <= 36);
+            assert!(spin_outcome(&[byte; 32], Wheel::European) <= 36);
+        }
+    }
+}
@@ -16,30 +16,79 @@ export class MagicRouletteClient {
     this.program = program;
   }
 
-  getBetPda(round: PublicKey, player: PublicKey) {
+  getBetPda(table: PublicKey, round: PublicKey, player: PublicKey) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("bet"), round.toBuffer(), player.toBuffer()],
+      [
+        Buffer.from("bet"),
+        table.toBuffer(),
+        round.toBuffer(),
+        player.toBuffer(),
+      ],
       this.program.programId
     )[0];
   }
 
-  getTablePda() {
+  getTablePda(tableId: BN) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("table")],
+      [Buffer.from("table"), tableId.toArrayLike(Buffer, "le", 8)],
       this.program.programId
     )[0];
   }
 
-  getVaultPda() {
+  getVaultPda(table: PublicKey) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("vault")],
+      [Buffer.from("vault"), table.toBuffer()],
       this.program.programId
     )[0];
   }
 
-  getRoundPda(roundNumber: BN) {
+  getFeeVaultPda(table: PublicKey) {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("round"), roundNumber.toArrayLike(Buffer, "le", 8)],
+      [Buffer.from("fee_vault"), table.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  getReferralPda(table: PublicKey, referrer: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("referral"), table.toBuffer(), referrer.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  getHistoryPda(table: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("history"), table.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  getRoundPda(table: PublicKey, roundNumber: BN) {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("round"),
+        table.toBuffer(),
+        roundNumber.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    )[0];
+  }
+
+  getLpPositionPda(table: PublicKey, provider: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("lp_position"), table.toBuffer(), provider.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  // stats are kept per denomination, lamport-denominated tables have no mint
+  getPlayerStatsPda(player: PublicKey, mint: PublicKey | null = null) {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("player_stats"),
+        player.toBuffer(),
+        (mint ?? PublicKey.default).toBuffer(),
+      ],
       this.program.programId
     )[0];
   }
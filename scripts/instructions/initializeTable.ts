@@ -1,38 +1,35 @@
 import { BN } from "@coral-xyz/anchor";
-import { admin, connection, program, vault } from "../setup";
-import { LAMPORTS_PER_SOL, sendAndConfirmTransaction, SystemProgram, Transaction } from "@solana/web3.js";
+import { admin, getTokenAccount, mint, program, tableId, tokenProgram } from "../setup";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 
 console.log("Initializing table...")
 
 // Params
 const minimumBetAmount = 1000; // in lamports
 const roundPeriodTs = 60; // in seconds
+const spinTimeoutTs = 120; // in seconds
+const claimWindowSecs = 60 * 60 * 24 * 7; // in seconds
+const oracleQueue = new PublicKey("Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh"); // MagicBlock default queue
+const initialVaultAmount = LAMPORTS_PER_SOL * 0.01; // in lamports, or base units of the mint
 
 const signature = await program.methods
-  .initializeTable(new BN(minimumBetAmount), new BN(roundPeriodTs))
-  .accounts({
+  .initializeTable(tableId, {
+    minimumBetAmount: new BN(minimumBetAmount),
+    roundPeriodTs: new BN(roundPeriodTs),
+    spinTimeoutTs: new BN(spinTimeoutTs),
+    claimWindowSecs: new BN(claimWindowSecs),
+    oracleQueue,
+    wheel: { american: {} },
+    zeroRule: { standard: {} },
+    initialVaultAmount: new BN(initialVaultAmount),
+  })
+  .accountsPartial({
     admin: admin.publicKey,
+    mint,
+    adminTokenAccount: mint ? getTokenAccount(admin.publicKey, mint) : null,
+    tokenProgram: mint ? tokenProgram : null,
   })
   .signers([admin])
   .rpc();
 
 console.log("Config initialized:", signature);
-
-const initialVaultFund = LAMPORTS_PER_SOL * 0.01;
-
-if (initialVaultFund > 0) {
-  console.log("Funding vault with", initialVaultFund / LAMPORTS_PER_SOL, "SOL");
-
-  const ix = SystemProgram.transfer({
-    fromPubkey: admin.publicKey,
-    toPubkey: vault,
-    lamports: initialVaultFund,
-  })
-
-  const tx = new Transaction().add(ix);
-  tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-  tx.feePayer = admin.publicKey;
-  const signature = await sendAndConfirmTransaction(connection, tx, [admin]);
-
-  console.log(`Vault funded: ${signature}`);
-}
\ No newline at end of file
@@ -0,0 +1,21 @@
+import { admin, magicRouletteClient, program, table } from "../setup"
+
+console.log("Retrying spin...")
+
+const tableAcc = await magicRouletteClient.fetchProgramAccount(table, "table");
+const currentRoundPda = magicRouletteClient.getRoundPda(table, tableAcc.currentRoundNumber);
+const nextRoundNumber = tableAcc.currentRoundNumber.addn(1);
+const newRoundPda = magicRouletteClient.getRoundPda(table, nextRoundNumber);
+
+const signature = await program.methods
+  .retrySpin()
+  .accountsPartial({
+    payer: admin.publicKey,
+    table,
+    currentRound: currentRoundPda,
+    newRound: newRoundPda,
+  })
+  .signers([admin])
+  .rpc();
+
+console.log("Spin retried:", signature);
@@ -0,0 +1,74 @@
+import { magicRouletteClient, admin, getTokenAccount, program, table, tokenProgram } from "../setup";
+
+console.log("Settling bets...")
+
+// Params
+const batchSize = 10; // bets settled per transaction
+
+const tableAcc = await magicRouletteClient.fetchProgramAccount(table, "table");
+// settle the last round by default, as the current round has no outcome yet
+const roundNumber = tableAcc.currentRoundNumber.subn(1);
+const roundPda = magicRouletteClient.getRoundPda(table, roundNumber);
+const currentRoundPda = magicRouletteClient.getRoundPda(table, tableAcc.currentRoundNumber);
+// token-denominated tables pay out to token accounts instead of wallets
+const mint = tableAcc.mint;
+
+// round is stored right after the discriminator and player of each bet
+const bets = await program.account.bet.all([
+  {
+    memcmp: {
+      offset: 8 + 32,
+      bytes: roundPda.toBase58(),
+    },
+  },
+]);
+
+console.log(`Found ${bets.length} bets in round ${roundNumber.toString()}`);
+
+for (let i = 0; i < bets.length; i += batchSize) {
+  const batch = bets.slice(i, i + batchSize);
+
+  const signature = await program.methods
+    .settleBets()
+    .accountsPartial({
+      cranker: admin.publicKey,
+      table,
+      round: roundPda,
+      currentRound: currentRoundPda,
+      mint,
+      crankerTokenAccount: mint ? getTokenAccount(admin.publicKey, mint) : null,
+      tokenProgram: mint ? tokenProgram : null,
+    })
+    .remainingAccounts(
+      batch.flatMap((bet) => [
+        {
+          pubkey: bet.publicKey,
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: bet.account.player,
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: magicRouletteClient.getPlayerStatsPda(bet.account.player, mint),
+          isSigner: false,
+          isWritable: true,
+        },
+        ...(mint
+          ? [
+              {
+                pubkey: getTokenAccount(bet.account.player, mint),
+                isSigner: false,
+                isWritable: true,
+              },
+            ]
+          : []),
+      ])
+    )
+    .signers([admin])
+    .rpc();
+
+  console.log("Bets settled:", signature);
+}
@@ -1,17 +1,17 @@
-import { admin, magicRouletteClient, program } from "../setup"
+import { admin, magicRouletteClient, program, table } from "../setup"
 
 console.log("Spinning roulette...")
 
-// const tableAcc = (await program.account.table.all())[0];
-const tableAcc = (await magicRouletteClient.fetchAllProgramAccounts("table"))[0];
-const currentRoundPda = magicRouletteClient.getRoundPda(tableAcc.account.currentRoundNumber);
-const nextRoundNumber = tableAcc.account.currentRoundNumber.addn(1);
-const newRoundPda = magicRouletteClient.getRoundPda(nextRoundNumber);
+const tableAcc = await magicRouletteClient.fetchProgramAccount(table, "table");
+const currentRoundPda = magicRouletteClient.getRoundPda(table, tableAcc.currentRoundNumber);
+const nextRoundNumber = tableAcc.currentRoundNumber.addn(1);
+const newRoundPda = magicRouletteClient.getRoundPda(table, nextRoundNumber);
 
 const signature = await program.methods
   .spinRoulette()
   .accountsPartial({
     payer: admin.publicKey,
+    table,
     currentRound: currentRoundPda,
     newRound: newRoundPda,
   })
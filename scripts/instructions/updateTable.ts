@@ -1,4 +1,4 @@
-import { admin, program } from "../setup";
+import { admin, program, table } from "../setup";
 import { BN } from "@coral-xyz/anchor";
 
 console.log("Updating table...")
@@ -6,12 +6,29 @@ console.log("Updating table...")
 // Params
 const minimumBetAmount = 1000; // in lamports
 const roundPeriodTs = 60; // in seconds
+const spinTimeoutTs = 120; // in seconds
 const newAdmin = admin.publicKey;
 
 const signature = await program.methods
-  .updateTable(new BN(minimumBetAmount), new BN(roundPeriodTs), newAdmin)
-  .accounts({
+  .updateTable({
+    minimumBetAmount: new BN(minimumBetAmount),
+    maximumBetAmounts: null,
+    maximumRoundLiability: null,
+    roundPeriodTs: new BN(roundPeriodTs),
+    spinTimeoutTs: new BN(spinTimeoutTs),
+    claimWindowSecs: null,
+    crankFee: null,
+    lpCooldownSecs: null,
+    rakeBps: null,
+    referralBps: null,
+    oracleQueue: null,
+    payoutMultipliers: null,
+    zeroRule: null,
+    newAdmin,
+  })
+  .accountsPartial({
     admin: admin.publicKey,
+    table,
   })
   .signers([admin])
   .rpc();
@@ -0,0 +1,18 @@
+import { admin, program, table } from "../setup";
+import { BN } from "@coral-xyz/anchor";
+
+console.log("Withdrawing fees...")
+
+// Params
+const amount = 1000; // in lamports
+
+const signature = await program.methods
+  .withdrawFees(new BN(amount))
+  .accountsPartial({
+    admin: admin.publicKey,
+    table,
+  })
+  .signers([admin])
+  .rpc();
+
+console.log("Fees withdrawn:", signature);
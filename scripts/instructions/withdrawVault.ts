@@ -1,4 +1,4 @@
-import { admin, program } from "../setup";
+import { admin, magicRouletteClient, program, table } from "../setup";
 import { BN } from "@coral-xyz/anchor";
 
 console.log("Withdrawing from vault...")
@@ -6,10 +6,15 @@ console.log("Withdrawing from vault...")
 // Params
 const amount = 1000; // in lamports
 
+const tableAcc = await magicRouletteClient.fetchProgramAccount(table, "table");
+const currentRoundPda = magicRouletteClient.getRoundPda(table, tableAcc.currentRoundNumber);
+
 const signature = await program.methods
   .withdrawVault(new BN(amount))
-  .accounts({
+  .accountsPartial({
     admin: admin.publicKey,
+    table,
+    currentRound: currentRoundPda,
   })
   .signers([admin])
   .rpc();
@@ -1,4 +1,4 @@
-import { AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
+import { AnchorProvider, BN, Program, Wallet } from "@coral-xyz/anchor";
 import { clusterApiUrl, Connection, Keypair, PublicKey } from "@solana/web3.js";
 import { MagicRoulette } from "../target/types/magic_roulette";
 import idl from "../target/idl/magic_roulette.json";
@@ -12,9 +12,20 @@ const provider = new AnchorProvider(connection, new Wallet(admin))
 export const program = new Program<MagicRoulette>(idl, provider);
 export const magicRouletteClient = new MagicRouletteClient(program);
 
-export const [vault] = PublicKey.findProgramAddressSync(
-  [
-    Buffer.from("vault"),
-  ],
-  program.programId
-)
\ No newline at end of file
+export const tableId = new BN(process.env.TABLE_ID || 0);
+export const table = magicRouletteClient.getTablePda(tableId);
+export const vault = magicRouletteClient.getVaultPda(table);
+// tables are lamport-denominated unless a mint is given
+export const mint = process.env.MINT ? new PublicKey(process.env.MINT) : null;
+// SPL Token by default, set to TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb for Token-2022 mints
+export const tokenProgram = new PublicKey(process.env.TOKEN_PROGRAM || "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+const associatedTokenProgram = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// associated token account of `owner` for `mint`, where token-denominated tables pay out to
+export function getTokenAccount(owner: PublicKey, mint: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [owner.toBuffer(), tokenProgram.toBuffer(), mint.toBuffer()],
+    associatedTokenProgram
+  )[0];
+}
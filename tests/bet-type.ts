@@ -2,6 +2,27 @@ import { IdlTypes } from "@coral-xyz/anchor";
 import { MagicRoulette } from "../target/types/magic_roulette";
 
 type BetType = IdlTypes<MagicRoulette>["betType"];
+type Position = IdlTypes<MagicRoulette>["position"];
+type ZeroRule = IdlTypes<MagicRoulette>["zeroRule"];
+
+// Bet kinds in the order payout multipliers are indexed by
+const BET_KINDS = [
+  "straightUp",
+  "split",
+  "street",
+  "corner",
+  "fiveNumber",
+  "line",
+  "column",
+  "dozen",
+  "red",
+  "black",
+  "even",
+  "odd",
+  "high",
+  "low",
+  "topLine",
+];
 
 // Red numbers in American roulette
 const RED_NUMBERS = [
@@ -96,5 +117,63 @@ export function isWinner(betType: BetType, outcome: number): boolean {
     return outcome >= 1 && outcome <= 18;
   }
 
+  if ("topLine" in betType) {
+    // Top line bet on single zero wheels: 0, 1, 2, 3
+    return [0, 1, 2, 3].includes(outcome);
+  }
+
   return false;
 }
+
+/**
+ * Check if a bet type pays 1 to 1
+ * @param betType - The bet type to check
+ * @returns true for red, black, even, odd, high and low bets
+ */
+export function isEvenMoney(betType: BetType): boolean {
+  return ["red", "black", "even", "odd", "high", "low"].some(
+    (kind) => kind in betType
+  );
+}
+
+/**
+ * Check if an outcome is 0 or 00
+ * @param outcome - The outcome number (0-36, or 37 for 00)
+ * @returns true if the outcome is a zero pocket
+ */
+export function isZero(outcome: number): boolean {
+  return outcome === 0 || outcome === 37;
+}
+
+/**
+ * Compute the amount owed for a position on a given outcome
+ * @param position - The position to settle
+ * @param outcome - The outcome number (0-36, or 37 for 00)
+ * @param payoutMultipliers - Payout multipliers of the round, indexed by bet kind
+ * @param zeroRule - Zero rule of the round
+ * @returns the stake plus winnings when the position wins, half the stake for even-money bets on zero under la partage, 0 otherwise
+ */
+export function settlement(
+  position: Position,
+  outcome: number,
+  payoutMultipliers: number[],
+  zeroRule: ZeroRule
+): number {
+  const amount = position.amount.toNumber();
+
+  if (isWinner(position.betType, outcome)) {
+    const kind = BET_KINDS.findIndex((kind) => kind in position.betType);
+
+    return amount * payoutMultipliers[kind] + amount;
+  }
+
+  if (
+    "laPartage" in zeroRule &&
+    isEvenMoney(position.betType) &&
+    isZero(outcome)
+  ) {
+    return Math.floor(amount / 2);
+  }
+
+  return 0;
+}
@@ -1 +1,7 @@
+import { PublicKey } from "@solana/web3.js";
+
 export const BASE_TX_FEE = 5000;
+// MagicBlock default VRF oracle queue
+export const DEFAULT_QUEUE = new PublicKey(
+  "Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh"
+);
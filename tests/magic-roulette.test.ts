@@ -1,6 +1,12 @@
 import { afterAll, beforeAll, describe, expect, test } from "bun:test";
 import { MagicRoulette } from "../target/types/magic_roulette";
-import { AnchorProvider, IdlTypes, Program, Wallet } from "@coral-xyz/anchor";
+import {
+  AnchorProvider,
+  IdlAccounts,
+  IdlTypes,
+  Program,
+  Wallet,
+} from "@coral-xyz/anchor";
 import {
   clusterApiUrl,
   Connection,
@@ -11,12 +17,19 @@ import {
 import { BN } from "@coral-xyz/anchor";
 import { MagicRouletteClient } from "./client";
 import idl from "../target/idl/magic_roulette.json";
-import { defundAccount, fundAccounts, skipBetAccIfExists } from "./utils";
+import {
+  defundAccount,
+  fundAccounts,
+  skipBetAccIfExists,
+  spinOutcome,
+} from "./utils";
 import { sleep } from "bun";
-import { isWinner } from "./bet-type";
-import { BASE_TX_FEE } from "./constants";
+import { settlement } from "./bet-type";
+import { BASE_TX_FEE, DEFAULT_QUEUE } from "./constants";
 
 type BetType = IdlTypes<MagicRoulette>["betType"];
+type Position = IdlTypes<MagicRoulette>["position"];
+type Round = IdlAccounts<MagicRoulette>["round"];
 
 describe("magic-roulette", () => {
   const connection = new Connection(
@@ -36,8 +49,15 @@ describe("magic-roulette", () => {
   // simulating with 12 players for 13 possible bet types
   const players = Array.from({ length: 12 }, () => Keypair.generate());
 
-  const tablePda = magicRouletteClient.getTablePda();
-  const vaultPda = magicRouletteClient.getVaultPda();
+  const tableId = new BN(0);
+  const tablePda = magicRouletteClient.getTablePda(tableId);
+  const vaultPda = magicRouletteClient.getVaultPda(tablePda);
+  const feeVaultPda = magicRouletteClient.getFeeVaultPda(tablePda);
+  // admin wallet refers the first player
+  const referralPda = magicRouletteClient.getReferralPda(
+    tablePda,
+    wallet.publicKey
+  );
 
   const possibleBetTypes: BetType[] = [
     { straightUp: { number: 0 } },
@@ -69,6 +89,8 @@ describe("magic-roulette", () => {
 
   // for the purpose of speed testing, set a short round period
   const roundPeriodTs = 45; // 45 secs
+  const spinTimeoutTs = 60; // 60 secs
+  const claimWindowSecs = 60 * 60 * 24; // 1 day
 
   test("initialize table", async () => {
     let tableAcc = await magicRouletteClient.fetchProgramAccount(
@@ -76,7 +98,7 @@ describe("magic-roulette", () => {
       "table"
     );
 
-    // table is a singleton, so this test only succeeds once per program deployed on a cluster
+    // table ids are unique, so this test only succeeds once per program deployed on a cluster
     if (tableAcc !== null) {
       console.log("Table already initialized, skipping...");
       return;
@@ -85,7 +107,17 @@ describe("magic-roulette", () => {
     const minimumBetAmount = 500; // 500 lamports
 
     await program.methods
-      .initializeTable(new BN(minimumBetAmount), new BN(roundPeriodTs))
+      .initializeTable(tableId, {
+        minimumBetAmount: new BN(minimumBetAmount),
+        roundPeriodTs: new BN(roundPeriodTs),
+        spinTimeoutTs: new BN(spinTimeoutTs),
+        claimWindowSecs: new BN(claimWindowSecs),
+        oracleQueue: DEFAULT_QUEUE,
+        wheel: { american: {} },
+        zeroRule: { standard: {} },
+        // fund vault to cover any potential winnings
+        initialVaultAmount: new BN(LAMPORTS_PER_SOL),
+      })
       .accounts({
         admin: wallet.publicKey,
       })
@@ -97,27 +129,50 @@ describe("magic-roulette", () => {
     expect(tableAcc.admin).toStrictEqual(wallet.payer.publicKey);
     expect(tableAcc.minimumBetAmount.toNumber()).toBe(minimumBetAmount);
     expect(tableAcc.roundPeriodTs.toNumber()).toBe(roundPeriodTs);
+    expect(tableAcc.spinTimeoutTs.toNumber()).toBe(spinTimeoutTs);
+    expect(tableAcc.claimWindowSecs.toNumber()).toBe(claimWindowSecs);
+    expect(tableAcc.forfeitedWinnings.toNumber()).toBe(0);
+    expect(tableAcc.oracleQueue).toStrictEqual(DEFAULT_QUEUE);
+    expect(tableAcc.wheel).toStrictEqual({ american: {} });
+    // no mint given, so the table is lamport-denominated
+    expect(tableAcc.mint).toBeNull();
 
     const vaultBal = await provider.connection.getBalance(vaultPda);
 
-    expect(vaultBal).toBeGreaterThan(0);
-
-    // fund vault to cover any potential winnings
-    await fundAccounts(
-      provider.connection,
-      wallet.payer,
-      [vaultPda],
-      LAMPORTS_PER_SOL
-    );
+    expect(vaultBal).toBeGreaterThan(LAMPORTS_PER_SOL);
   });
 
   test("update table", async () => {
     const minimumBetAmount = 1000; // 1000 lamports
+    const maximumRoundLiability = LAMPORTS_PER_SOL / 2;
+    const crankFee = 10; // 10 lamports per paid out bet, 1% of the minimum bet
+    const rakeBps = 100; // 1%
+    const referralBps = 50; // half of the rake
+    // straight up pays 34 instead of 35, everything else is standard
+    const tighterPayoutMultipliers = [
+      34, 17, 11, 8, 6, 5, 2, 2, 1, 1, 1, 1, 1, 1, 8,
+    ];
 
     await program.methods
-      .updateTable(new BN(minimumBetAmount), null, null)
-      .accounts({
+      .updateTable({
+        minimumBetAmount: new BN(minimumBetAmount),
+        maximumBetAmounts: null,
+        maximumRoundLiability: new BN(maximumRoundLiability),
+        roundPeriodTs: null,
+        spinTimeoutTs: null,
+        claimWindowSecs: null,
+        crankFee: new BN(crankFee),
+        lpCooldownSecs: null,
+        rakeBps,
+        referralBps,
+        oracleQueue: null,
+        payoutMultipliers: tighterPayoutMultipliers,
+        zeroRule: { laPartage: {} },
+        newAdmin: null,
+      })
+      .accountsPartial({
         admin: wallet.publicKey,
+        table: tablePda,
       })
       .signers([wallet.payer])
       .rpc();
@@ -129,6 +184,14 @@ describe("magic-roulette", () => {
 
     expect(tableAcc.minimumBetAmount.toNumber()).toBe(minimumBetAmount);
     expect(tableAcc.roundPeriodTs.toNumber()).toBe(roundPeriodTs);
+    expect(tableAcc.payoutMultipliers).toStrictEqual(tighterPayoutMultipliers);
+    expect(tableAcc.zeroRule).toStrictEqual({ laPartage: {} });
+    expect(tableAcc.maximumRoundLiability.toNumber()).toBe(
+      maximumRoundLiability
+    );
+    expect(tableAcc.crankFee.toNumber()).toBe(crankFee);
+    expect(tableAcc.rakeBps).toBe(rakeBps);
+    expect(tableAcc.referralBps).toBe(referralBps);
   });
 
   test("place bet for all players", async () => {
@@ -137,9 +200,13 @@ describe("magic-roulette", () => {
       "table"
     );
     const currentRoundNumber = tableAcc.currentRoundNumber;
-    const roundPda = magicRouletteClient.getRoundPda(currentRoundNumber);
+    const roundPda = magicRouletteClient.getRoundPda(
+      tablePda,
+      currentRoundNumber
+    );
 
     const betAmount = new BN(1000); // 1000 lamports
+    const preFeeVaultBal = await provider.connection.getBalance(feeVaultPda);
 
     for (let i = 0; i < players.length; i++) {
       console.log(`Placing bet for player ${i + 1}...`);
@@ -147,25 +214,125 @@ describe("magic-roulette", () => {
 
       const betType: BetType = possibleBetTypes[i % possibleBetTypes.length];
 
-      const betPda = magicRouletteClient.getBetPda(roundPda, player.publicKey);
+      const betPda = magicRouletteClient.getBetPda(
+        tablePda,
+        roundPda,
+        player.publicKey
+      );
       await skipBetAccIfExists(magicRouletteClient, betPda);
 
       await program.methods
         .placeBet(betType, betAmount)
-        .accounts({
+        .accountsPartial({
           player: player.publicKey,
+          table: tablePda,
+          playerStats: magicRouletteClient.getPlayerStatsPda(player.publicKey),
         })
         .signers([player])
         .rpc({ commitment: "confirmed" });
     }
 
+    const referralAcc = await magicRouletteClient.fetchProgramAccount(
+      referralPda,
+      "referral"
+    );
+
+    if (referralAcc === null) {
+      await program.methods
+        .registerReferral()
+        .accountsPartial({
+          referrer: wallet.publicKey,
+          table: tablePda,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: "confirmed" });
+    }
+
+    // first player spreads a second position across the layout in the same round, referred by the admin
+    await program.methods
+      .placeBet({ dozen: { dozen: 3 } }, betAmount)
+      .accountsPartial({
+        player: players[0].publicKey,
+        table: tablePda,
+        playerStats: magicRouletteClient.getPlayerStatsPda(
+          players[0].publicKey
+        ),
+        referral: referralPda,
+      })
+      .signers([players[0]])
+      .rpc({ commitment: "confirmed" });
+
+    const postReferralAcc = await magicRouletteClient.fetchProgramAccount(
+      referralPda,
+      "referral"
+    );
+
+    expect(postReferralAcc.accruedRewards.toNumber()).toBe(
+      (referralAcc?.accruedRewards.toNumber() ?? 0) +
+        Math.floor((betAmount.toNumber() * tableAcc.referralBps) / 10000)
+    );
+
+    const betAcc = await magicRouletteClient.fetchProgramAccount(
+      magicRouletteClient.getBetPda(tablePda, roundPda, players[0].publicKey),
+      "bet"
+    );
+
+    expect(betAcc.positions.length).toBe(2);
+    expect(betAcc.amount.toNumber()).toBe(betAmount.toNumber() * 2);
+
+    // second player places several positions in a single instruction
+    await program.methods
+      .placeBets([
+        { betType: { red: {} }, amount: betAmount },
+        { betType: { straightUp: { number: 17 } }, amount: betAmount },
+      ])
+      .accountsPartial({
+        player: players[1].publicKey,
+        table: tablePda,
+        playerStats: magicRouletteClient.getPlayerStatsPda(
+          players[1].publicKey
+        ),
+      })
+      .signers([players[1]])
+      .rpc({ commitment: "confirmed" });
+
+    const batchBetAcc = await magicRouletteClient.fetchProgramAccount(
+      magicRouletteClient.getBetPda(tablePda, roundPda, players[1].publicKey),
+      "bet"
+    );
+
+    expect(batchBetAcc.positions.length).toBe(3);
+
+    // lifetime stats count every position across rounds
+    const playerStatsAcc = await magicRouletteClient.fetchProgramAccount(
+      magicRouletteClient.getPlayerStatsPda(players[1].publicKey),
+      "playerStats"
+    );
+
+    expect(playerStatsAcc.betsPlaced.toNumber()).toBeGreaterThanOrEqual(3);
+    expect(playerStatsAcc.totalWagered.toNumber()).toBeGreaterThanOrEqual(
+      betAmount.toNumber() * 3
+    );
+
     const roundAcc = await magicRouletteClient.fetchProgramAccount(
       roundPda,
       "round"
     );
 
     expect(roundAcc.poolAmount.toNumber()).toBe(
-      players.length * betAmount.toNumber()
+      (players.length + 3) * betAmount.toNumber()
+    );
+
+    // rake is charged on top of every position
+    const rake = Math.floor((betAmount.toNumber() * tableAcc.rakeBps) / 10000);
+    const postFeeVaultBal = await provider.connection.getBalance(feeVaultPda);
+
+    expect(postFeeVaultBal).toBe(preFeeVaultBal + (players.length + 3) * rake);
+
+    // straight up on 17 pays at least 34 to 1 on top of the stake, besides every other bet covering 17,
+    // depending on the multipliers the round was opened with
+    expect(roundAcc.exposure[17].toNumber()).toBeGreaterThanOrEqual(
+      betAmount.toNumber() * 35
     );
   });
 
@@ -178,8 +345,12 @@ describe("magic-roulette", () => {
     );
     const currentRoundNumber = tableAcc.currentRoundNumber;
 
-    currentRoundPda = magicRouletteClient.getRoundPda(currentRoundNumber);
+    currentRoundPda = magicRouletteClient.getRoundPda(
+      tablePda,
+      currentRoundNumber
+    );
     const newRoundPda = magicRouletteClient.getRoundPda(
+      tablePda,
       currentRoundNumber.addn(1)
     );
 
@@ -197,6 +368,7 @@ describe("magic-roulette", () => {
       .spinRoulette()
       .accountsPartial({
         payer: wallet.publicKey,
+        table: tablePda,
         currentRound: currentRoundPda,
         newRound: newRoundPda,
       })
@@ -209,6 +381,7 @@ describe("magic-roulette", () => {
     );
 
     expect(currentRoundAcc.isSpun).toBe(true);
+    expect(currentRoundAcc.callerSeed.some((byte) => byte !== 0)).toBe(true);
   });
 
   let outcome: number;
@@ -223,6 +396,39 @@ describe("magic-roulette", () => {
       if (currentRoundAcc.outcome !== null) {
         console.log("outcome:", currentRoundAcc.outcome);
         outcome = currentRoundAcc.outcome;
+
+        // settlement totals match the bookkeeping done when placing bets
+        expect(currentRoundAcc.totalPayout.toNumber()).toBe(
+          currentRoundAcc.exposure[outcome].toNumber()
+        );
+        expect(currentRoundAcc.totalWinningStake.toNumber()).toBe(
+          currentRoundAcc.winningStakes[outcome].toNumber()
+        );
+
+        // randomness is kept on the round so the outcome can be verified later
+        const tableAcc = await magicRouletteClient.fetchProgramAccount(
+          tablePda,
+          "table"
+        );
+
+        expect(spinOutcome(currentRoundAcc.randomness, tableAcc.wheel)).toBe(
+          outcome
+        );
+
+        // latest spin is appended to the history of the table
+        const historyAcc = await magicRouletteClient.fetchProgramAccount(
+          magicRouletteClient.getHistoryPda(tablePda),
+          "history"
+        );
+        const latestEntry =
+          historyAcc.entries[
+            (historyAcc.count.toNumber() - 1) % historyAcc.entries.length
+          ];
+
+        expect(latestEntry.roundNumber.toNumber()).toBe(
+          currentRoundAcc.roundNumber.toNumber()
+        );
+        expect(latestEntry.outcome).toBe(outcome);
         break;
       }
 
@@ -231,6 +437,18 @@ describe("magic-roulette", () => {
     }
   });
 
+  // rounds settle with the payout multipliers and zero rule they were opened with,
+  // which may differ from the table settings updated since
+  function hasPayout(
+    positions: Position[],
+    { outcome, payoutMultipliers, zeroRule }: Round
+  ) {
+    return positions.some(
+      (position) =>
+        settlement(position, outcome, payoutMultipliers, zeroRule) > 0
+    );
+  }
+
   test("claim winnings", async () => {
     let roundAcc = await magicRouletteClient.fetchProgramAccount(
       currentRoundPda,
@@ -240,9 +458,11 @@ describe("magic-roulette", () => {
     // wait a bit to ensure all bets are finalized
     await sleep(500);
 
+    // last player is left to the settle bets crank
     await Promise.all(
-      players.map(async (player, i) => {
+      players.slice(0, -1).map(async (player, i) => {
         const betPda = magicRouletteClient.getBetPda(
+          tablePda,
           currentRoundPda,
           player.publicKey
         );
@@ -251,7 +471,7 @@ describe("magic-roulette", () => {
           "bet"
         );
 
-        if (isWinner(betAcc.betType, roundAcc.outcome)) {
+        if (hasPayout(betAcc.positions, roundAcc)) {
           console.log(`Player ${i + 1} has winning bet, claiming winnings...`);
 
           const prePlayerBal = await provider.connection.getBalance(
@@ -260,8 +480,12 @@ describe("magic-roulette", () => {
 
           await program.methods
             .claimWinnings()
-            .accounts({
+            .accountsPartial({
               player: player.publicKey,
+              table: tablePda,
+              playerStats: magicRouletteClient.getPlayerStatsPda(
+                player.publicKey
+              ),
             })
             .remainingAccounts([
               {
@@ -283,21 +507,256 @@ describe("magic-roulette", () => {
           );
 
           expect(prePlayerBal).toBeLessThan(postPlayerBal);
+
+          // claimed bet is closed and its rent returned
+          expect(
+            await magicRouletteClient.fetchProgramAccount(betPda, "bet")
+          ).toBeNull();
         }
       })
     );
   });
 
+  test("settle bets", async () => {
+    const player = players[players.length - 1];
+    const betPda = magicRouletteClient.getBetPda(
+      tablePda,
+      currentRoundPda,
+      player.publicKey
+    );
+    const tableAcc = await magicRouletteClient.fetchProgramAccount(
+      tablePda,
+      "table"
+    );
+    const roundAcc = await magicRouletteClient.fetchProgramAccount(
+      currentRoundPda,
+      "round"
+    );
+    const betAcc = await magicRouletteClient.fetchProgramAccount(betPda, "bet");
+    // crank fee is only paid for bets that pay out
+    const crankFee = hasPayout(betAcc.positions, roundAcc)
+      ? tableAcc.crankFee.toNumber()
+      : 0;
+
+    const preCrankerBal = await provider.connection.getBalance(
+      wallet.publicKey
+    );
+    const prePlayerBal = await provider.connection.getBalance(player.publicKey);
+
+    await program.methods
+      .settleBets()
+      .accountsPartial({
+        cranker: wallet.publicKey,
+        table: tablePda,
+        round: currentRoundPda,
+        currentRound: magicRouletteClient.getRoundPda(
+          tablePda,
+          tableAcc.currentRoundNumber
+        ),
+      })
+      .remainingAccounts([
+        {
+          pubkey: betPda,
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: player.publicKey,
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: magicRouletteClient.getPlayerStatsPda(player.publicKey),
+          isSigner: false,
+          isWritable: true,
+        },
+      ])
+      .signers([wallet.payer])
+      .rpc();
+
+    const postCrankerBal = await provider.connection.getBalance(
+      wallet.publicKey
+    );
+    const postPlayerBal = await provider.connection.getBalance(
+      player.publicKey
+    );
+
+    // winnings, if any, and rent of the bet go straight to the player
+    expect(prePlayerBal).toBeLessThan(postPlayerBal);
+    expect(postCrankerBal).toBe(
+      preCrankerBal + crankFee - BASE_TX_FEE
+    );
+    expect(
+      await magicRouletteClient.fetchProgramAccount(betPda, "bet")
+    ).toBeNull();
+  });
+
+  test("close losing bets", async () => {
+    const roundAcc = await magicRouletteClient.fetchProgramAccount(
+      currentRoundPda,
+      "round"
+    );
+
+    for (let i = 0; i < players.length; i++) {
+      const player = players[i];
+      const betPda = magicRouletteClient.getBetPda(
+        tablePda,
+        currentRoundPda,
+        player.publicKey
+      );
+      const betAcc = await magicRouletteClient.fetchProgramAccount(
+        betPda,
+        "bet"
+      );
+
+      if (betAcc === null || hasPayout(betAcc.positions, roundAcc)) {
+        continue;
+      }
+
+      console.log(`Player ${i + 1} has losing bet, closing it...`);
+
+      const prePlayerBal = await provider.connection.getBalance(
+        player.publicKey
+      );
+
+      await program.methods
+        .closeLosingBet()
+        .accountsPartial({
+          player: player.publicKey,
+          table: tablePda,
+          round: currentRoundPda,
+          bet: betPda,
+        })
+        .rpc();
+
+      const postPlayerBal = await provider.connection.getBalance(
+        player.publicKey
+      );
+
+      expect(prePlayerBal).toBeLessThan(postPlayerBal);
+      expect(
+        await magicRouletteClient.fetchProgramAccount(betPda, "bet")
+      ).toBeNull();
+    }
+  });
+
+  test("close round", async () => {
+    const roundAcc = await magicRouletteClient.fetchProgramAccount(
+      currentRoundPda,
+      "round"
+    );
+
+    // every bet of the round has been claimed or closed
+    expect(roundAcc.betCount).toBe(0);
+
+    const prePayerBal = await provider.connection.getBalance(roundAcc.payer);
+
+    await program.methods
+      .closeRound()
+      .accountsPartial({
+        payer: roundAcc.payer,
+        table: tablePda,
+        round: currentRoundPda,
+      })
+      .rpc();
+
+    const postPayerBal = await provider.connection.getBalance(roundAcc.payer);
+
+    expect(prePayerBal).toBeLessThan(postPayerBal);
+    expect(
+      await magicRouletteClient.fetchProgramAccount(currentRoundPda, "round")
+    ).toBeNull();
+  });
+
+  test("deposit and withdraw liquidity", async () => {
+    const lp = players[0];
+    const lpPositionPda = magicRouletteClient.getLpPositionPda(
+      tablePda,
+      lp.publicKey
+    );
+    const depositAmount = LAMPORTS_PER_SOL * 0.01;
+
+    let tableAcc = await magicRouletteClient.fetchProgramAccount(
+      tablePda,
+      "table"
+    );
+    const currentRoundPda = magicRouletteClient.getRoundPda(
+      tablePda,
+      tableAcc.currentRoundNumber
+    );
+    const preLpShares = tableAcc.lpShares.toNumber();
+
+    await program.methods
+      .lpDeposit(new BN(depositAmount))
+      .accountsPartial({
+        provider: lp.publicKey,
+        table: tablePda,
+        currentRound: currentRoundPda,
+      })
+      .signers([lp])
+      .rpc();
+
+    const lpPositionAcc = await magicRouletteClient.fetchProgramAccount(
+      lpPositionPda,
+      "lpPosition"
+    );
+    tableAcc = await magicRouletteClient.fetchProgramAccount(tablePda, "table");
+
+    expect(lpPositionAcc.shares.toNumber()).toBeGreaterThan(0);
+    expect(tableAcc.lpShares.toNumber()).toBe(
+      preLpShares + lpPositionAcc.shares.toNumber()
+    );
+    // bankroll funded by the admin is held as house shares
+    expect(tableAcc.totalShares.toNumber()).toBeGreaterThan(
+      tableAcc.lpShares.toNumber()
+    );
+
+    const preLpBal = await provider.connection.getBalance(lp.publicKey);
+
+    // no cooldown on the test table, so shares can be withdrawn right away
+    await program.methods
+      .lpWithdraw(lpPositionAcc.shares)
+      .accountsPartial({
+        provider: lp.publicKey,
+        table: tablePda,
+        currentRound: currentRoundPda,
+      })
+      .signers([lp])
+      .rpc();
+
+    const postLpBal = await provider.connection.getBalance(lp.publicKey);
+    tableAcc = await magicRouletteClient.fetchProgramAccount(tablePda, "table");
+
+    expect(preLpBal).toBeLessThan(postLpBal);
+    expect(tableAcc.lpShares.toNumber()).toBe(preLpShares);
+    expect(
+      await magicRouletteClient.fetchProgramAccount(lpPositionPda, "lpPosition")
+    ).toBeNull();
+  });
+
   test("withdraw from vault", async () => {
     const minRent = await connection.getMinimumBalanceForRentExemption(0);
     const preVaultBal = await provider.connection.getBalance(vaultPda);
     const preAdminBal = await provider.connection.getBalance(wallet.publicKey);
-    const withdrawAmount = (preVaultBal - minRent) / 2; // withdraw half of vault balance
+    const tableAcc = await magicRouletteClient.fetchProgramAccount(
+      tablePda,
+      "table"
+    );
+    const currentRoundPda = magicRouletteClient.getRoundPda(
+      tablePda,
+      tableAcc.currentRoundNumber
+    );
+    // winnings owed to players stay in the vault
+    const withdrawableBal =
+      preVaultBal - minRent - tableAcc.unclaimedLiability.toNumber();
+    const withdrawAmount = Math.floor(withdrawableBal / 2); // withdraw half of withdrawable balance
 
     await program.methods
       .withdrawVault(new BN(withdrawAmount))
-      .accounts({
+      .accountsPartial({
         admin: wallet.publicKey,
+        table: tablePda,
+        currentRound: currentRoundPda,
       })
       .signers([wallet.payer])
       .rpc();
@@ -311,6 +770,73 @@ describe("magic-roulette", () => {
     expect(preVaultBal).toBe(postVaultBal + withdrawAmount);
   });
 
+  test("claim referral rewards", async () => {
+    const referralAcc = await magicRouletteClient.fetchProgramAccount(
+      referralPda,
+      "referral"
+    );
+    const preReferrerBal = await provider.connection.getBalance(
+      wallet.publicKey
+    );
+
+    await program.methods
+      .claimReferralRewards()
+      .accountsPartial({
+        referrer: wallet.publicKey,
+        table: tablePda,
+      })
+      .signers([wallet.payer])
+      .rpc();
+
+    const postReferrerBal = await provider.connection.getBalance(
+      wallet.publicKey
+    );
+
+    expect(postReferrerBal).toBe(
+      preReferrerBal + referralAcc.accruedRewards.toNumber() - BASE_TX_FEE
+    );
+
+    const postReferralAcc = await magicRouletteClient.fetchProgramAccount(
+      referralPda,
+      "referral"
+    );
+
+    expect(postReferralAcc.accruedRewards.toNumber()).toBe(0);
+  });
+
+  test("withdraw fees", async () => {
+    const minRent = await connection.getMinimumBalanceForRentExemption(0);
+    const preFeeVaultBal = await provider.connection.getBalance(feeVaultPda);
+    const preAdminBal = await provider.connection.getBalance(wallet.publicKey);
+    const tableAcc = await magicRouletteClient.fetchProgramAccount(
+      tablePda,
+      "table"
+    );
+    // referral rewards stay in the fee vault until claimed
+    const withdrawAmount =
+      preFeeVaultBal - minRent - tableAcc.unclaimedReferralRewards.toNumber();
+
+    // withdraw all collected rake
+    await program.methods
+      .withdrawFees(null)
+      .accountsPartial({
+        admin: wallet.publicKey,
+        table: tablePda,
+      })
+      .signers([wallet.payer])
+      .rpc();
+
+    const postAdminBal = await provider.connection.getBalance(wallet.publicKey);
+
+    expect(preAdminBal).toBe(postAdminBal - withdrawAmount + BASE_TX_FEE);
+
+    const postFeeVaultBal = await provider.connection.getBalance(feeVaultPda);
+
+    expect(postFeeVaultBal).toBe(
+      minRent + tableAcc.unclaimedReferralRewards.toNumber()
+    );
+  });
+
   afterAll(async () => {
     // defund all accounts used in testing
     console.log("Defunding wallets...");
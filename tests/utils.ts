@@ -1,4 +1,4 @@
-import { Address } from "@coral-xyz/anchor";
+import { Address, IdlTypes } from "@coral-xyz/anchor";
 import {
   Connection,
   Keypair,
@@ -9,6 +9,9 @@ import {
   Transaction,
 } from "@solana/web3.js";
 import { MagicRouletteClient } from "./client";
+import { MagicRoulette } from "../target/types/magic_roulette";
+
+type Wheel = IdlTypes<MagicRoulette>["wheel"];
 
 // fund multiple accounts at once
 export async function fundAccounts(
@@ -61,3 +64,15 @@ export async function skipBetAccIfExists(
     console.log("Bet account already exists, skipping...");
   }
 }
+
+// derive the outcome of a spin from the randomness stored on the round, same as `spin_outcome`
+export function spinOutcome(randomness: number[], wheel: Wheel): number {
+  const range = "american" in wheel ? 38 : 37;
+  // bytes are scanned from the end for one that maps onto the wheel without modulo bias
+  const threshold = Math.floor(256 / range) * range;
+  const byte =
+    [...randomness].reverse().find((byte) => byte < threshold) ??
+    randomness[31];
+
+  return byte % range;
+}